@@ -0,0 +1,19 @@
+//! Links a CBLAS implementation when the `blas` feature is enabled.
+//!
+//! `TENSOR_BLAS_LIB` names the library in `rustc-link-lib` syntax, so a kind may be given
+//! too: `openblas` (the default), `cblas`, `blis`, `static=openblas` or
+//! `framework=Accelerate`. `TENSOR_BLAS_LIB_DIR` adds a directory to the search path.
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=TENSOR_BLAS_LIB");
+    println!("cargo:rerun-if-env-changed=TENSOR_BLAS_LIB_DIR");
+    if std::env::var_os("CARGO_FEATURE_BLAS").is_none() {
+        return;
+    }
+
+    if let Ok(dir) = std::env::var("TENSOR_BLAS_LIB_DIR") {
+        println!("cargo:rustc-link-search=native={dir}");
+    }
+    let lib = std::env::var("TENSOR_BLAS_LIB").unwrap_or_else(|_| "openblas".to_string());
+    println!("cargo:rustc-link-lib={lib}");
+}
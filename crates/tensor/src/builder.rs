@@ -0,0 +1,174 @@
+use crate::error::Result;
+use crate::shape::Shape;
+use crate::types::{BaseTensor, Tensor};
+use crate::view::check_layout;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Step-by-step constructor for tensors with a custom layout.
+///
+/// Every setting is optional: the shape defaults to a 1-D tensor covering the whole buffer,
+/// the strides default to row-major for that shape, and the offset defaults to zero.
+/// [`TensorBuilder::build`] checks that the resulting view stays inside the buffer.
+///
+/// ```
+/// use tensor::builder::TensorBuilder;
+///
+/// let t = TensorBuilder::new()
+///     .data(vec![1, 2, 3, 4, 5, 6])
+///     .shape(&[3, 2])
+///     .strides(&[1, 3])
+///     .build()
+///     .unwrap();
+/// assert_eq!(t.to_vec(), vec![1, 4, 2, 5, 3, 6]);
+/// ```
+pub struct TensorBuilder<T> {
+    data: Vec<T>,
+    shape: Option<Shape>,
+    strides: Option<Vec<isize>>,
+    offset: usize,
+    requires_grad: bool,
+}
+
+impl<T> Default for TensorBuilder<T> {
+    fn default() -> Self {
+        TensorBuilder {
+            data: Vec::new(),
+            shape: None,
+            strides: None,
+            offset: 0,
+            requires_grad: false,
+        }
+    }
+}
+
+impl<T> TensorBuilder<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn data(mut self, data: Vec<T>) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn shape(mut self, shape: &[usize]) -> Self {
+        self.shape = Some(shape.into());
+        self
+    }
+
+    pub fn strides(mut self, strides: &[isize]) -> Self {
+        self.strides = Some(strides.to_vec());
+        self
+    }
+
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn requires_grad(mut self, requires_grad: bool) -> Self {
+        self.requires_grad = requires_grad;
+        self
+    }
+
+    /// Validates the layout against the buffer and produces the tensor.
+    pub fn build(self) -> Result<Tensor<T>> {
+        let shape = self
+            .shape
+            .unwrap_or_else(|| Shape::from(vec![self.data.len()]));
+        let strides = self.strides.unwrap_or_else(|| shape.default_strides());
+
+        check_layout(&shape, &strides, self.offset, self.data.len())?;
+
+        Ok(Tensor {
+            base: BaseTensor {
+                data: Rc::new(RefCell::new(self.data)),
+                shape,
+                strides,
+                offset: self.offset,
+            },
+            requires_grad: self.requires_grad,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TensorBuilder;
+    use crate::error::TensorError;
+
+    #[test]
+    fn defaults() {
+        let t = TensorBuilder::new()
+            .data(vec![1.0, 2.0, 3.0])
+            .requires_grad(true)
+            .build()
+            .unwrap();
+
+        assert_eq!(t.shape(), &[3]);
+        assert_eq!(t.strides(), &[1]);
+        assert!(t.requires_grad);
+    }
+
+    #[test]
+    fn offset_view() {
+        let t = TensorBuilder::new()
+            .data((0..10).collect())
+            .shape(&[2, 2])
+            .offset(6)
+            .build()
+            .unwrap();
+
+        assert_eq!(t.to_vec(), vec![6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn negative_strides() {
+        let reversed = TensorBuilder::new()
+            .data(vec![1, 2, 3])
+            .strides(&[-1])
+            .offset(2)
+            .build()
+            .unwrap();
+        let underflow = TensorBuilder::new()
+            .data(vec![1, 2, 3])
+            .strides(&[-1])
+            .offset(1)
+            .build();
+
+        assert_eq!(reversed.to_vec(), vec![3, 2, 1]);
+        assert!(underflow.is_err());
+    }
+
+    #[test]
+    fn rejects_inconsistent_layouts() {
+        let out_of_bounds = TensorBuilder::new()
+            .data(vec![0; 4])
+            .shape(&[2, 2])
+            .offset(1)
+            .build();
+        let wrong_rank = TensorBuilder::new()
+            .data(vec![0; 4])
+            .shape(&[2, 2])
+            .strides(&[1])
+            .build();
+
+        assert_eq!(
+            out_of_bounds.err(),
+            Some(TensorError::ViewOutOfBounds {
+                shape: vec![2, 2],
+                strides: vec![2, 1],
+                offset: 1,
+                buffer_len: 4,
+            })
+        );
+        assert_eq!(
+            wrong_rank.err(),
+            Some(TensorError::StridesMismatch {
+                shape: vec![2, 2],
+                strides: vec![1],
+            })
+        );
+    }
+}
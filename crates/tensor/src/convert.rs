@@ -0,0 +1,161 @@
+use crate::error::{Result, TensorError};
+use crate::types::Tensor;
+use std::cell::Ref;
+
+/// Flattens equally sized `rows` into one buffer, reporting the first ragged row.
+fn flatten_rows<T: Clone, R: AsRef<[T]>>(rows: &[R]) -> Result<(Vec<T>, [usize; 2])> {
+    let cols = rows.first().map_or(0, |row| row.as_ref().len());
+    let mut data = Vec::with_capacity(rows.len() * cols);
+    for (i, row) in rows.iter().enumerate() {
+        let row = row.as_ref();
+        if row.len() != cols {
+            return Err(TensorError::RaggedInput {
+                row: i,
+                expected: cols,
+                found: row.len(),
+            });
+        }
+        data.extend_from_slice(row);
+    }
+    Ok((data, [rows.len(), cols]))
+}
+
+impl<T> From<Vec<T>> for Tensor<T> {
+    /// Builds a 1-D tensor.
+    fn from(data: Vec<T>) -> Self {
+        let len = data.len();
+        Tensor::from_vec(data, &[len])
+    }
+}
+
+impl<T: Clone> From<Vec<Vec<T>>> for Tensor<T> {
+    /// Builds a 2-D tensor with one row per inner `Vec`.
+    ///
+    /// Panics if the rows have different lengths; use [`TryFrom<&[&[T]]>`] to handle that case.
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let (data, shape) = flatten_rows(&rows).unwrap_or_else(|e| panic!("{}", e));
+        Tensor::from_vec(data, &shape)
+    }
+}
+
+impl<T: Clone> TryFrom<&[&[T]]> for Tensor<T> {
+    type Error = TensorError;
+
+    /// Builds a 2-D tensor with one row per inner slice, rejecting ragged input.
+    fn try_from(rows: &[&[T]]) -> Result<Self> {
+        let (data, shape) = flatten_rows(rows)?;
+        Ok(Tensor::from_vec(data, &shape))
+    }
+}
+
+impl<T: Clone> Tensor<T> {
+    /// Copies the logical elements into a contiguous `Vec` in row-major order, honouring
+    /// shape, strides, and offset.
+    pub fn to_vec(&self) -> Vec<T> {
+        let data = self.base.data.borrow();
+        self.base
+            .storage_indices()
+            .map(|i| data[i].clone())
+            .collect()
+    }
+
+    /// Extracts the value of a single-element tensor, such as a loss or a full reduction.
+    pub fn item(&self) -> Result<T> {
+        if self.numel() != 1 {
+            return Err(TensorError::NotScalar {
+                shape: self.shape().to_vec(),
+            });
+        }
+        Ok(self.base.data.borrow()[self.base.offset].clone())
+    }
+}
+
+impl<T> Tensor<T> {
+    /// Borrows the elements as a slice if they are laid out contiguously in row-major order,
+    /// or returns `None` for strided views.
+    pub fn try_as_slice(&self) -> Option<Ref<'_, [T]>> {
+        if !self.is_contiguous() {
+            return None;
+        }
+        let start = self.base.offset;
+        let end = start + self.numel();
+        Some(Ref::map(self.base.data.borrow(), |data| &data[start..end]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::TensorError;
+    use crate::types::{BaseTensor, Tensor};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn transposed() -> Tensor<i32> {
+        Tensor {
+            base: BaseTensor {
+                data: Rc::new(RefCell::new(vec![1, 2, 3, 4, 5, 6])),
+                shape: vec![3, 2].into(),
+                strides: vec![1, 3],
+                offset: 0,
+            },
+            requires_grad: false,
+        }
+    }
+
+    #[test]
+    fn to_vec_respects_strides() {
+        assert_eq!(transposed().to_vec(), vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn try_as_slice() {
+        let contiguous = Tensor {
+            base: BaseTensor {
+                data: Rc::new(RefCell::new(vec![0, 1, 2, 3, 4])),
+                shape: vec![2, 2].into(),
+                strides: vec![2, 1],
+                offset: 1,
+            },
+            requires_grad: false,
+        };
+
+        assert_eq!(&*contiguous.try_as_slice().unwrap(), &[1, 2, 3, 4]);
+        assert!(transposed().try_as_slice().is_none());
+    }
+
+    #[test]
+    fn from_vecs() {
+        let flat = Tensor::from(vec![1, 2, 3]);
+        let nested: Tensor<i32> = vec![vec![1, 2, 3], vec![4, 5, 6]].into();
+
+        assert_eq!(flat.shape(), &[3]);
+        assert_eq!(nested.shape(), &[2, 3]);
+        assert_eq!(nested.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn try_from_slices_rejects_ragged() {
+        let rows: &[&[i32]] = &[&[1, 2], &[3, 4]];
+        let ragged: &[&[i32]] = &[&[1, 2], &[3]];
+
+        assert_eq!(Tensor::try_from(rows).unwrap().shape(), &[2, 2]);
+        assert_eq!(
+            Tensor::try_from(ragged).err(),
+            Some(TensorError::RaggedInput {
+                row: 1,
+                expected: 2,
+                found: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn item() {
+        assert_eq!(Tensor::scalar(3.5).item(), Ok(3.5));
+        assert_eq!(Tensor::from_vec(vec![7], &[1, 1]).item(), Ok(7));
+        assert_eq!(
+            transposed().item(),
+            Err(TensorError::NotScalar { shape: vec![3, 2] })
+        );
+    }
+}
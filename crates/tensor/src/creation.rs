@@ -0,0 +1,344 @@
+use crate::error::{Result, TensorError};
+use crate::shape::Shape;
+use crate::types::{BaseTensor, Tensor};
+use num_traits::{Float, Num, NumCast, One, Zero};
+use std::mem::MaybeUninit;
+
+impl<T> Tensor<T> {
+    /// Creates a zero-dimensional tensor holding a single `value`.
+    pub fn scalar(value: T) -> Self {
+        Tensor::from_vec(vec![value], &[])
+    }
+
+    /// Allocates a contiguous tensor of the given `shape` without initializing its elements.
+    ///
+    /// Intended for kernel output buffers where every element is overwritten anyway, saving the
+    /// extra pass over memory that [`Tensor::zeros`] would cost. Write every element (e.g. with
+    /// [`MaybeUninit::write`] or [`Tensor::fill_`]) and then call [`Tensor::assume_init`].
+    pub fn empty(shape: &[usize]) -> Tensor<MaybeUninit<T>> {
+        let numel = Shape::from(shape)
+            .checked_numel()
+            .unwrap_or_else(|| panic!("Tensor shape {:?} overflows usize", shape));
+        let mut data = Vec::with_capacity(numel);
+        // SAFETY: `MaybeUninit<T>` does not require initialization, and the capacity is `numel`.
+        unsafe { data.set_len(numel) };
+        Tensor::from_vec(data, shape)
+    }
+}
+
+impl<T> Tensor<MaybeUninit<T>> {
+    /// Converts a tensor created by [`Tensor::empty`] into an initialized one without copying.
+    ///
+    /// Panics if the buffer is shared with another tensor.
+    ///
+    /// # Safety
+    ///
+    /// Every element of the buffer must have been initialized.
+    pub unsafe fn assume_init(self) -> Tensor<T> {
+        let BaseTensor {
+            data,
+            shape,
+            strides,
+            offset,
+        } = self.base;
+        let data = match std::rc::Rc::try_unwrap(data) {
+            Ok(data) => data.into_inner(),
+            Err(_) => panic!("Tensor::assume_init requires exclusive ownership of the buffer"),
+        };
+        // SAFETY: the caller guarantees every element is initialized. The collect reuses the
+        // allocation since `MaybeUninit<T>` and `T` have the same layout.
+        let data = data
+            .into_iter()
+            .map(|value| unsafe { value.assume_init() })
+            .collect::<Vec<_>>();
+
+        Tensor {
+            base: BaseTensor {
+                data: std::rc::Rc::new(std::cell::RefCell::new(data)),
+                shape,
+                strides,
+                offset,
+            },
+            requires_grad: self.requires_grad,
+        }
+    }
+}
+
+impl<T: Clone> Tensor<T> {
+    /// Creates a contiguous tensor of the given `shape` with every element set to `value`.
+    ///
+    /// Panics if the number of elements described by `shape` overflows `usize`.
+    pub fn full(shape: &[usize], value: T) -> Self {
+        let numel = Shape::from(shape)
+            .checked_numel()
+            .unwrap_or_else(|| panic!("Tensor shape {:?} overflows usize", shape));
+        Tensor::from_vec(vec![value; numel], shape)
+    }
+
+    /// Creates a contiguous tensor shaped like `other` with every element set to `value`.
+    pub fn full_like(other: &Tensor<T>, value: T) -> Self {
+        Tensor::full(other.shape(), value)
+    }
+}
+
+impl<T: Clone + Zero> Tensor<T> {
+    /// Creates a tensor of the given `shape` filled with zeros.
+    pub fn zeros(shape: &[usize]) -> Self {
+        Tensor::full(shape, T::zero())
+    }
+
+    /// Creates a tensor of zeros shaped like `other`.
+    pub fn zeros_like(other: &Tensor<T>) -> Self {
+        Tensor::zeros(other.shape())
+    }
+
+    /// Creates a square 2-D tensor with `values` on the main diagonal and zeros elsewhere.
+    pub fn diag(values: &[T]) -> Self {
+        let n = values.len();
+        let mut data = vec![T::zero(); n * n];
+        for (i, value) in values.iter().enumerate() {
+            data[i * n + i] = value.clone();
+        }
+        Tensor::from_vec(data, &[n, n])
+    }
+}
+
+impl<T: Clone + One> Tensor<T> {
+    /// Creates a tensor of the given `shape` filled with ones.
+    pub fn ones(shape: &[usize]) -> Self {
+        Tensor::full(shape, T::one())
+    }
+
+    /// Creates a tensor of ones shaped like `other`.
+    pub fn ones_like(other: &Tensor<T>) -> Self {
+        Tensor::ones(other.shape())
+    }
+}
+
+impl<T: Clone + Zero + One> Tensor<T> {
+    /// Creates an `n x n` identity matrix.
+    pub fn eye(n: usize) -> Self {
+        Tensor::diag(&vec![T::one(); n])
+    }
+}
+
+/// Axis ordering used by [`Tensor::meshgrid`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeshIndexing {
+    /// Matrix indexing: output dimension `i` follows input `i`.
+    Ij,
+    /// Cartesian indexing: like `Ij` but with the first two output dimensions swapped,
+    /// matching NumPy's default.
+    Xy,
+}
+
+impl<T: Clone> Tensor<T> {
+    /// Builds coordinate grids from 1-D `tensors`, one output per input, all sharing the
+    /// same shape.
+    pub fn meshgrid(tensors: &[&Tensor<T>], indexing: MeshIndexing) -> Result<Vec<Tensor<T>>> {
+        if let Some(t) = tensors.iter().find(|t| t.ndim() != 1) {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: t.ndim(),
+            });
+        }
+
+        // `axes[k]` is the output dimension that input `k` varies along.
+        let mut axes = (0..tensors.len()).collect::<Vec<_>>();
+        if indexing == MeshIndexing::Xy && axes.len() >= 2 {
+            axes.swap(0, 1);
+        }
+        let mut shape = Shape::from(vec![0; tensors.len()]);
+        for (t, &axis) in tensors.iter().zip(&axes) {
+            shape[axis] = t.numel();
+        }
+        let strides = shape.default_strides();
+        let numel = shape.numel();
+
+        Ok(tensors
+            .iter()
+            .zip(&axes)
+            .map(|(t, &axis)| {
+                let values = t.to_vec();
+                let data = (0..numel)
+                    .map(|flat| values[flat / strides[axis] as usize % shape[axis]].clone())
+                    .collect();
+                Tensor::from_vec(data, &shape)
+            })
+            .collect())
+    }
+}
+
+impl<T: Num + NumCast + Copy> Tensor<T> {
+    /// Creates a 1-D tensor with values from `start` (inclusive) to `end` (exclusive), spaced by `step`.
+    ///
+    /// Panics if `step` is zero or the range is not finite.
+    pub fn arange(start: T, end: T, step: T) -> Self {
+        if step.is_zero() {
+            panic!("Tensor::arange step must be non-zero");
+        }
+
+        // Compute the length up front so floating point accumulation cannot add a stray element.
+        let (start_f64, step_f64) = (start.to_f64().unwrap(), step.to_f64().unwrap());
+        let steps = (end.to_f64().unwrap() - start_f64) / step_f64;
+        if !steps.is_finite() {
+            panic!("Tensor::arange range must be finite");
+        }
+        let len = steps.ceil().max(0.0) as usize;
+
+        // An index can be out of range for `T` even when the value it produces is not, as in
+        // `arange(-100i8, 100, 1)`; such elements are computed in `f64` instead.
+        let data = (0..len)
+            .map(|i| match T::from(i) {
+                Some(i) => start + step * i,
+                None => T::from(start_f64 + step_f64 * i as f64).unwrap(),
+            })
+            .collect::<Vec<_>>();
+        Tensor::from_vec(data, &[len])
+    }
+}
+
+impl<T: Float> Tensor<T> {
+    /// Creates a 1-D tensor of `steps` values evenly spaced from `start` to `end`, both inclusive.
+    pub fn linspace(start: T, end: T, steps: usize) -> Self {
+        let data = match steps {
+            0 => Vec::new(),
+            1 => vec![start],
+            _ => {
+                let last = T::from(steps - 1).unwrap();
+                let delta = (end - start) / last;
+                let mut data = (0..steps - 1)
+                    .map(|i| start + delta * T::from(i).unwrap())
+                    .collect::<Vec<_>>();
+                data.push(end);
+                data
+            }
+        };
+        Tensor::from_vec(data, &[steps])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MeshIndexing;
+    use crate::types::Tensor;
+
+    #[test]
+    fn zeros() {
+        let t = Tensor::<f32>::zeros(&[2, 3]);
+
+        assert_eq!(t.shape(), &[2, 3]);
+        assert_eq!(t.strides(), &[3, 1]);
+        assert_eq!(*t.base.data.borrow(), vec![0.0; 6]);
+    }
+
+    #[test]
+    fn ones_and_full() {
+        let ones = Tensor::<i32>::ones(&[2, 2, 2]);
+        let full = Tensor::full(&[3], 7);
+
+        assert_eq!(ones.strides(), &[4, 2, 1]);
+        assert_eq!(*ones.base.data.borrow(), vec![1; 8]);
+        assert_eq!(*full.base.data.borrow(), vec![7, 7, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn full_rejects_overflowing_shape() {
+        let _ = Tensor::full(&[usize::MAX, 2], 0u8);
+    }
+
+    #[test]
+    fn scalar() {
+        let t = Tensor::scalar(2.5);
+
+        assert_eq!(t.ndim(), 0);
+        assert_eq!(t.numel(), 1);
+        assert_eq!(t.to_vec(), vec![2.5]);
+    }
+
+    #[test]
+    fn empty_then_initialize() {
+        let t = Tensor::<u32>::empty(&[2, 3]);
+        for (i, slot) in t.base.data.borrow_mut().iter_mut().enumerate() {
+            slot.write(i as u32);
+        }
+
+        // SAFETY: every element was written above.
+        let t = unsafe { t.assume_init() };
+
+        assert_eq!(t.shape(), &[2, 3]);
+        assert_eq!(t.to_vec(), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn like_constructors() {
+        let template = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[3, 2]);
+
+        assert_eq!(Tensor::zeros_like(&template).to_vec(), vec![0.0; 6]);
+        assert_eq!(Tensor::ones_like(&template).shape(), &[3, 2]);
+        assert_eq!(Tensor::full_like(&template, 4.0).to_vec(), vec![4.0; 6]);
+    }
+
+    #[test]
+    fn arange() {
+        let ints = Tensor::arange(0, 10, 3);
+        let down = Tensor::arange(5, 0, -2);
+        let floats = Tensor::arange(0.0, 1.0, 0.25);
+
+        assert_eq!(*ints.base.data.borrow(), vec![0, 3, 6, 9]);
+        assert_eq!(*down.base.data.borrow(), vec![5, 3, 1]);
+        assert_eq!(*floats.base.data.borrow(), vec![0.0, 0.25, 0.5, 0.75]);
+    }
+
+    #[test]
+    fn arange_narrow_integers() {
+        let t = Tensor::<i8>::arange(-100, 100, 1);
+
+        assert_eq!(t.numel(), 200);
+        assert_eq!(t.to_vec()[..2], [-100, -99]);
+        assert_eq!(t.to_vec()[198..], [98, 99]);
+        assert_eq!(Tensor::<u8>::arange(0, 255, 1).to_vec()[254], 254);
+    }
+
+    #[test]
+    #[should_panic(expected = "finite")]
+    fn arange_rejects_infinite_range() {
+        let _ = Tensor::arange(0.0, f64::INFINITY, 1.0);
+    }
+
+    #[test]
+    fn linspace() {
+        let t = Tensor::linspace(0.0, 1.0, 5);
+
+        assert_eq!(t.shape(), &[5]);
+        assert_eq!(*t.base.data.borrow(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(Tensor::<f64>::linspace(2.0, 3.0, 1).numel(), 1);
+    }
+
+    #[test]
+    fn meshgrid() {
+        let x = Tensor::from(vec![1, 2, 3]);
+        let y = Tensor::from(vec![10, 20]);
+
+        let ij = Tensor::meshgrid(&[&x, &y], MeshIndexing::Ij).unwrap();
+        let xy = Tensor::meshgrid(&[&x, &y], MeshIndexing::Xy).unwrap();
+
+        assert_eq!(ij[0].shape(), &[3, 2]);
+        assert_eq!(ij[0].to_vec(), vec![1, 1, 2, 2, 3, 3]);
+        assert_eq!(ij[1].to_vec(), vec![10, 20, 10, 20, 10, 20]);
+        assert_eq!(xy[0].shape(), &[2, 3]);
+        assert_eq!(xy[0].to_vec(), vec![1, 2, 3, 1, 2, 3]);
+        assert_eq!(xy[1].to_vec(), vec![10, 10, 10, 20, 20, 20]);
+    }
+
+    #[test]
+    fn eye_and_diag() {
+        let eye = Tensor::<i32>::eye(3);
+        let diag = Tensor::diag(&[1, 2]);
+
+        assert_eq!(eye.shape(), &[3, 3]);
+        assert_eq!(*eye.base.data.borrow(), vec![1, 0, 0, 0, 1, 0, 0, 0, 1]);
+        assert_eq!(*diag.base.data.borrow(), vec![1, 0, 0, 2]);
+    }
+}
@@ -0,0 +1,139 @@
+use thiserror::Error;
+
+/// Errors reported by fallible tensor operations.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum TensorError {
+    /// The operation needs exactly one element, but the tensor has a different shape.
+    #[error("expected a tensor with exactly one element, got shape {shape:?}")]
+    NotScalar { shape: Vec<usize> },
+    /// Two operands were required to have the same shape.
+    #[error("shape mismatch: left {left:?} vs right {right:?}")]
+    ShapeMismatch { left: Vec<usize>, right: Vec<usize> },
+    /// Two shapes cannot be broadcast against each other.
+    #[error("shapes {left:?} and {right:?} cannot be broadcast together")]
+    BroadcastMismatch { left: Vec<usize>, right: Vec<usize> },
+    /// The number of elements described by a shape overflows `usize`.
+    #[error("number of elements in shape {shape:?} overflows usize")]
+    ShapeOverflow { shape: Vec<usize> },
+    /// A strides vector does not have one entry per dimension.
+    #[error("strides {strides:?} do not match the rank of shape {shape:?}")]
+    StridesMismatch {
+        shape: Vec<usize>,
+        strides: Vec<isize>,
+    },
+    /// A dimension index is not valid for a tensor of rank `ndim`.
+    #[error("dimension {dim} is out of range for a tensor with {ndim} dimensions")]
+    DimOutOfRange { dim: isize, ndim: usize },
+    /// A dimension range ends before it starts.
+    #[error("start dimension {start} comes after end dimension {end}")]
+    InvalidDimRange { start: usize, end: usize },
+    /// A dimension expected to have size 1 has a different size.
+    #[error("dimension {dim} has size {size}, expected size 1")]
+    NotSingletonDim { dim: usize, size: usize },
+    /// A list of dimensions is not a permutation of `0..ndim`.
+    #[error("{dims:?} is not a permutation of the {ndim} dimensions")]
+    InvalidPermutation { dims: Vec<usize>, ndim: usize },
+    /// A reshape target is incompatible with the tensor's number of elements.
+    #[error("cannot reshape tensor of shape {shape:?} into {target:?}")]
+    InvalidReshape {
+        shape: Vec<usize>,
+        target: Vec<isize>,
+    },
+    /// A tensor does not have the number of dimensions an operation requires.
+    #[error("expected a tensor with {expected} dimensions, got {actual}")]
+    RankMismatch { expected: usize, actual: usize },
+    /// Nested input rows do not all have the same length.
+    #[error("ragged input: row {row} has {found} elements but {expected} were expected")]
+    RaggedInput {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A view would read past the end of its buffer.
+    #[error(
+        "view with shape {shape:?}, strides {strides:?} and offset {offset} does not fit in a buffer of {buffer_len} elements"
+    )]
+    ViewOutOfBounds {
+        shape: Vec<usize>,
+        strides: Vec<isize>,
+        offset: usize,
+        buffer_len: usize,
+    },
+    /// A range along a dimension extends past its size.
+    #[error("range {start}..{} is out of bounds for dimension {dim} of size {size}", start.saturating_add(*len))]
+    SliceOutOfRange {
+        dim: usize,
+        start: usize,
+        len: usize,
+        size: usize,
+    },
+    /// Two argument lists that must pair up have different lengths.
+    #[error("expected {expected} entries to match, got {actual}")]
+    LengthMismatch { expected: usize, actual: usize },
+    /// An operation that needs at least one input tensor was given none.
+    #[error("expected at least one input tensor")]
+    EmptyInput,
+    /// A padding amount is too large for the chosen mode.
+    #[error("padding of {pad} is invalid for dimension {dim} of size {size}")]
+    InvalidPadding { dim: usize, pad: usize, size: usize },
+    /// A sliding window does not fit its dimension, or its step is zero.
+    #[error("window of size {size} with step {step} is invalid for a dimension of size {dim_size}")]
+    InvalidWindow {
+        size: usize,
+        step: usize,
+        dim_size: usize,
+    },
+    /// An element index lies outside the tensor's shape.
+    #[error("index {index:?} is out of bounds for shape {shape:?}")]
+    IndexOutOfBounds {
+        index: Vec<usize>,
+        shape: Vec<usize>,
+    },
+    /// The operation needs a row-major contiguous tensor.
+    #[error("expected a contiguous tensor, got shape {shape:?} with strides {strides:?}")]
+    NonContiguous {
+        shape: Vec<usize>,
+        strides: Vec<isize>,
+    },
+    /// An integer index does not address an element of the dimension it selects from.
+    #[error("index {index} is out of range for a dimension of size {size}")]
+    IndexOutOfRange { index: i64, size: usize },
+    /// An in-place write targets a view in which several elements share one buffer slot.
+    #[error(
+        "cannot write in place to a view of shape {shape:?} with strides {strides:?} whose elements overlap"
+    )]
+    InternalOverlap {
+        shape: Vec<usize>,
+        strides: Vec<isize>,
+    },
+    /// A reduction without an identity element is applied over an empty dimension.
+    #[error("cannot reduce over dimension {dim}, which has size 0")]
+    EmptyReduction { dim: usize },
+    /// An operation that counts occurrences was given a negative value.
+    #[error("expected non-negative values, got {value}")]
+    NegativeValue { value: i64 },
+    /// An einsum equation is malformed or does not fit its operands.
+    #[error("invalid einsum equation {equation:?}: {reason}")]
+    InvalidEinsum { equation: String, reason: String },
+    /// The same dimension was given twice where two distinct dimensions are required.
+    #[error("dimension {dim} is given more than once")]
+    RepeatedDim { dim: usize },
+    /// The operation needs square matrices in the last two dimensions.
+    #[error("expected square matrices, got shape {shape:?}")]
+    NotSquare { shape: Vec<usize> },
+    /// A matrix that must be invertible is singular.
+    #[error("matrix is singular")]
+    SingularMatrix,
+    /// A matrix that must be symmetric positive-definite is not.
+    #[error("matrix is not positive-definite: the leading minor of order {order} is not positive")]
+    NotPositiveDefinite { order: usize },
+    /// A numeric argument lies outside the range the operation accepts.
+    #[error("{name} must be in {range}, got {value}")]
+    ArgumentOutOfRange {
+        name: &'static str,
+        range: &'static str,
+        value: String,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, TensorError>;
@@ -0,0 +1,563 @@
+use crate::error::{Result, TensorError};
+use crate::manipulation::gather_from;
+use crate::shape::Shape;
+use crate::types::{Tensor, for_each_index};
+use crate::view::{broadcast_shapes, check_dim};
+use std::ops::Add;
+
+/// Converts an integer index into a position along a dimension of `size` elements.
+pub(crate) fn check_index(index: i64, size: usize) -> Result<usize> {
+    usize::try_from(index)
+        .ok()
+        .filter(|&i| i < size)
+        .ok_or(TensorError::IndexOutOfRange { index, size })
+}
+
+/// Checks that `index` has the rank of `shape` and is no larger along any dimension,
+/// except `dim` when `any_size_along_dim` is set.
+fn check_index_shape(
+    shape: &[usize],
+    index: &[usize],
+    dim: usize,
+    any_size_along_dim: bool,
+) -> Result<()> {
+    if index.len() != shape.len() {
+        return Err(TensorError::RankMismatch {
+            expected: shape.len(),
+            actual: index.len(),
+        });
+    }
+    let fits = (0..shape.len()).all(|d| (any_size_along_dim && d == dim) || index[d] <= shape[d]);
+    if !fits {
+        return Err(TensorError::ShapeMismatch {
+            left: shape.to_vec(),
+            right: index.to_vec(),
+        });
+    }
+    Ok(())
+}
+
+impl<T> Tensor<T> {
+    /// Buffer position of the element at `index`, or `None` if `index` has the wrong rank or
+    /// lies outside the shape.
+    pub(crate) fn checked_storage_offset(&self, index: &[usize]) -> Option<usize> {
+        let in_bounds =
+            index.len() == self.ndim() && index.iter().zip(self.shape()).all(|(&i, &dim)| i < dim);
+        in_bounds.then(|| self.base.storage_offset(index))
+    }
+}
+
+impl<T: Clone> Tensor<T> {
+    /// Reads the element at the multi-index `index`, respecting strides and offset.
+    ///
+    /// Returns `None` if `index` does not have one entry per dimension or is out of bounds.
+    pub fn get(&self, index: &[usize]) -> Option<T> {
+        let position = self.checked_storage_offset(index)?;
+        Some(self.base.data.borrow()[position].clone())
+    }
+
+    /// Writes `value` to the element at the multi-index `index`, writing into the shared buffer.
+    pub fn set(&self, index: &[usize], value: T) -> Result<()> {
+        let position =
+            self.checked_storage_offset(index)
+                .ok_or_else(|| TensorError::IndexOutOfBounds {
+                    index: index.to_vec(),
+                    shape: self.shape().to_vec(),
+                })?;
+        self.base.data.borrow_mut()[position] = value;
+        Ok(())
+    }
+
+    /// Picks the slices at `indices` along `dim`, in order, into a new contiguous tensor.
+    ///
+    /// `indices` must be 1-D; the result has `indices.numel()` entries along `dim` and
+    /// matches `self` elsewhere. Indices may repeat.
+    pub fn index_select(&self, dim: usize, indices: &Tensor<i64>) -> Result<Tensor<T>> {
+        check_dim(dim, self.ndim())?;
+        if indices.ndim() != 1 {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: indices.ndim(),
+            });
+        }
+        let positions = indices
+            .to_vec()
+            .into_iter()
+            .map(|i| check_index(i, self.shape()[dim]))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut shape = self.shape().to_vec();
+        shape[dim] = positions.len();
+        Ok(gather_from(&self.base, &shape, |index, src| {
+            src.copy_from_slice(index);
+            src[dim] = positions[index[dim]];
+        }))
+    }
+
+    /// Reads `self` at the positions along `dim` given by `index`, with PyTorch semantics:
+    /// for `dim == 0`, `out[i][j] = self[index[i][j]][j]`.
+    ///
+    /// `index` must have the same rank as `self` and be no larger in any other dimension.
+    /// The result has the shape of `index`.
+    pub fn gather(&self, dim: usize, index: &Tensor<i64>) -> Result<Tensor<T>> {
+        check_dim(dim, self.ndim())?;
+        check_index_shape(self.shape(), index.shape(), dim, true)?;
+        let positions = index
+            .to_vec()
+            .into_iter()
+            .map(|i| check_index(i, self.shape()[dim]))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut positions = positions.into_iter();
+        Ok(gather_from(&self.base, index.shape(), |index, src| {
+            src.copy_from_slice(index);
+            src[dim] = positions.next().expect("one position per output element");
+        }))
+    }
+
+    /// Returns a copy of `self` with the elements of `src` written to the positions along
+    /// `dim` given by `index`, the inverse of [`Tensor::gather`]: for `dim == 0`,
+    /// `out[index[i][j]][j] = src[i][j]`.
+    ///
+    /// `index` must be no larger than `src` in any dimension, and no larger than `self` in
+    /// any dimension but `dim`. When an index repeats, the last write wins.
+    pub fn scatter(&self, dim: usize, index: &Tensor<i64>, src: &Tensor<T>) -> Result<Tensor<T>> {
+        self.scatter_with(dim, index, src, |slot, value| *slot = value)
+    }
+
+    /// Shared implementation of the scatter family: `combine` merges each `src` element into
+    /// its destination slot.
+    fn scatter_with(
+        &self,
+        dim: usize,
+        index: &Tensor<i64>,
+        src: &Tensor<T>,
+        mut combine: impl FnMut(&mut T, T),
+    ) -> Result<Tensor<T>> {
+        check_dim(dim, self.ndim())?;
+        check_index_shape(self.shape(), index.shape(), dim, true)?;
+        check_index_shape(src.shape(), index.shape(), dim, false)?;
+        let positions = index
+            .to_vec()
+            .into_iter()
+            .map(|i| check_index(i, self.shape()[dim]))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut result = self.to_vec();
+        let strides = Shape::from(self.shape()).default_strides();
+        let src_data = src.base.data.borrow();
+        let mut positions = positions.into_iter();
+        for_each_index(index.shape(), |index| {
+            let position = positions.next().expect("one position per index element");
+            let flat = index
+                .iter()
+                .zip(&strides)
+                .enumerate()
+                .map(|(d, (&i, &stride))| if d == dim { position } else { i } * stride as usize)
+                .sum::<usize>();
+            combine(
+                &mut result[flat],
+                src_data[src.base.storage_offset(index)].clone(),
+            );
+        });
+        Ok(Tensor::from_vec(result, self.shape()))
+    }
+
+    /// Advanced (integer-array) indexing with NumPy semantics.
+    ///
+    /// `indices` has one entry per leading dimension: `Some(tensor)` selects positions
+    /// along that dimension, `None` (and any dimension past the end of `indices`) keeps it
+    /// whole. The index tensors are broadcast together to a shape `B`. If the indexed
+    /// dimensions are adjacent, `B` replaces them in the result; otherwise `B` comes first,
+    /// followed by the remaining dimensions in order.
+    pub fn index(&self, indices: &[Option<&Tensor<i64>>]) -> Result<Tensor<T>> {
+        if indices.len() > self.ndim() {
+            return Err(TensorError::LengthMismatch {
+                expected: self.ndim(),
+                actual: indices.len(),
+            });
+        }
+        let indexed = (0..indices.len())
+            .filter(|&d| indices[d].is_some())
+            .collect::<Vec<_>>();
+        let kept = (0..self.ndim())
+            .filter(|d| !indexed.contains(d))
+            .collect::<Vec<_>>();
+
+        let mut batch = Vec::new();
+        for index in indices.iter().flatten() {
+            batch = broadcast_shapes(&batch, index.shape())?;
+        }
+        // `positions[k]` holds, for every element of `batch`, the position along `indexed[k]`.
+        let positions = indexed
+            .iter()
+            .map(|&d| {
+                let index = indices[d].expect("indexed dimensions have an index tensor");
+                index
+                    .broadcast_to(&batch)?
+                    .to_vec()
+                    .into_iter()
+                    .map(|i| check_index(i, self.shape()[d]))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let adjacent = indexed.windows(2).all(|pair| pair[1] == pair[0] + 1);
+        let batch_start = match indexed.first() {
+            Some(&first) if adjacent => first,
+            _ => 0,
+        };
+        let mut shape = kept.iter().map(|&d| self.shape()[d]).collect::<Vec<_>>();
+        shape.splice(batch_start..batch_start, batch.iter().copied());
+
+        let batch_strides = Shape::from(batch.as_slice()).default_strides();
+        Ok(gather_from(&self.base, &shape, |index, src| {
+            let batch_index = &index[batch_start..batch_start + batch.len()];
+            let flat = batch_index
+                .iter()
+                .zip(&batch_strides)
+                .map(|(&i, &stride)| i * stride as usize)
+                .sum::<usize>();
+            for (&d, positions) in indexed.iter().zip(&positions) {
+                src[d] = positions[flat];
+            }
+            let rest = index[..batch_start]
+                .iter()
+                .chain(&index[batch_start + batch.len()..]);
+            for (&d, &i) in kept.iter().zip(rest) {
+                src[d] = i;
+            }
+        }))
+    }
+
+    /// Reads the elements at `indices`, treating `self` as flattened in row-major order.
+    /// The result has the shape of `indices`.
+    pub fn take(&self, indices: &Tensor<i64>) -> Result<Tensor<T>> {
+        let positions = self.base.storage_indices().collect::<Vec<_>>();
+        let data = self.base.data.borrow();
+        let values = indices
+            .to_vec()
+            .into_iter()
+            .map(|i| Ok(data[positions[check_index(i, positions.len())?]].clone()))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Tensor::from_vec(values, indices.shape()))
+    }
+
+    /// Collects the elements where `mask` is `true` into a new 1-D tensor, in row-major order.
+    ///
+    /// `self` and `mask` are broadcast to a common shape first.
+    pub fn masked_select(&self, mask: &Tensor<bool>) -> Result<Tensor<T>> {
+        let shape = broadcast_shapes(self.shape(), mask.shape())?;
+        let values = self.broadcast_to(&shape)?.to_vec();
+        let mask = mask.broadcast_to(&shape)?.to_vec();
+
+        let selected = values
+            .into_iter()
+            .zip(mask)
+            .filter_map(|(value, selected)| selected.then_some(value))
+            .collect::<Vec<_>>();
+        let len = selected.len();
+        Ok(Tensor::from_vec(selected, &[len]))
+    }
+
+    /// Picks each element from `a` where `cond` is `true` and from `b` elsewhere.
+    ///
+    /// All three tensors are broadcast to a common shape.
+    pub fn where_(cond: &Tensor<bool>, a: &Tensor<T>, b: &Tensor<T>) -> Result<Tensor<T>> {
+        let shape = broadcast_shapes(&broadcast_shapes(cond.shape(), a.shape())?, b.shape())?;
+        let cond = cond.broadcast_to(&shape)?.to_vec();
+        let a = a.broadcast_to(&shape)?.to_vec();
+        let b = b.broadcast_to(&shape)?.to_vec();
+
+        let values = cond
+            .into_iter()
+            .zip(a.into_iter().zip(b))
+            .map(|(take_a, (a, b))| if take_a { a } else { b })
+            .collect();
+        Ok(Tensor::from_vec(values, &shape))
+    }
+
+    /// Returns a copy of `self` with the elements where `mask` is `true` set to `value`.
+    /// See [`Tensor::masked_fill_`] for the in-place version.
+    pub fn masked_fill(&self, mask: &Tensor<bool>, value: T) -> Result<Tensor<T>> {
+        let result = Tensor::from_vec(self.to_vec(), self.shape());
+        result.masked_fill_(mask, value)?;
+        Ok(result)
+    }
+}
+
+impl<T: Clone + Add<Output = T>> Tensor<T> {
+    /// Like [`Tensor::scatter`], but adds each element of `src` to its destination instead
+    /// of overwriting it, so repeated indices accumulate.
+    pub fn scatter_add(
+        &self,
+        dim: usize,
+        index: &Tensor<i64>,
+        src: &Tensor<T>,
+    ) -> Result<Tensor<T>> {
+        self.scatter_with(dim, index, src, |slot, value| *slot = slot.clone() + value)
+    }
+}
+
+impl<T: PartialEq + Default> Tensor<T> {
+    /// Multi-indices of the elements that differ from `T::default()` (zero for numbers,
+    /// `false` for `bool`), as an `[n, ndim]` tensor in row-major order.
+    pub fn nonzero(&self) -> Tensor<i64> {
+        let data = self.base.data.borrow();
+        let zero = T::default();
+        let mut indices = Vec::new();
+        let mut count = 0;
+        let mut positions = self.base.storage_indices();
+        for_each_index(self.shape(), |index| {
+            let position = positions.next().expect("one position per element");
+            if data[position] != zero {
+                indices.extend(index.iter().map(|&i| i as i64));
+                count += 1;
+            }
+        });
+        Tensor::from_vec(indices, &[count, self.ndim()])
+    }
+
+    /// Alias of [`Tensor::nonzero`], following NumPy's naming.
+    pub fn argwhere(&self) -> Tensor<i64> {
+        self.nonzero()
+    }
+}
+
+impl<T: Clone + PartialOrd> Tensor<T> {
+    /// For each of `values`, the position in the ascending 1-D `sorted_sequence` where it
+    /// would be inserted to keep the sequence sorted. With `right`, the position after any
+    /// equal elements is returned instead of the one before them.
+    ///
+    /// The result has the shape of `values`.
+    pub fn searchsorted(
+        sorted_sequence: &Tensor<T>,
+        values: &Tensor<T>,
+        right: bool,
+    ) -> Result<Tensor<i64>> {
+        if sorted_sequence.ndim() != 1 {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: sorted_sequence.ndim(),
+            });
+        }
+        let sorted = sorted_sequence.to_vec();
+        let positions = values
+            .to_vec()
+            .into_iter()
+            .map(|value| {
+                let position = if right {
+                    sorted.partition_point(|x| *x <= value)
+                } else {
+                    sorted.partition_point(|x| *x < value)
+                };
+                position as i64
+            })
+            .collect();
+        Ok(Tensor::from_vec(positions, values.shape()))
+    }
+
+    /// Index of the bucket each element falls into, given ascending 1-D `boundaries`.
+    /// Equivalent to [`Tensor::searchsorted`] with `self` as the values.
+    pub fn bucketize(&self, boundaries: &Tensor<T>, right: bool) -> Result<Tensor<i64>> {
+        Tensor::searchsorted(boundaries, self, right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    #[test]
+    fn get_respects_strides() {
+        let t = crate::tensor![[1, 2, 3], [4, 5, 6]];
+        let transposed = t.transpose(0, 1).unwrap();
+
+        assert_eq!(t.get(&[1, 2]), Some(6));
+        assert_eq!(transposed.get(&[2, 0]), Some(3));
+        assert_eq!(t.get(&[2, 0]), None);
+        assert_eq!(t.get(&[0]), None);
+    }
+
+    #[test]
+    fn set_writes_through_view() {
+        let t = Tensor::from_vec(vec![0; 6], &[2, 3]);
+        let transposed = t.transpose(0, 1).unwrap();
+
+        transposed.set(&[2, 1], 7).unwrap();
+
+        assert_eq!(t.to_vec(), vec![0, 0, 0, 0, 0, 7]);
+        assert_eq!(
+            t.set(&[0, 3], 1),
+            Err(TensorError::IndexOutOfBounds {
+                index: vec![0, 3],
+                shape: vec![2, 3],
+            })
+        );
+    }
+
+    #[test]
+    fn index_select() {
+        let t = crate::tensor![[1, 2, 3], [4, 5, 6]];
+        let indices = Tensor::from(vec![2i64, 0, 2]);
+
+        let cols = t.index_select(1, &indices).unwrap();
+        let rows = t.index_select(0, &Tensor::from(vec![1i64])).unwrap();
+
+        assert_eq!(cols.shape(), &[2, 3]);
+        assert_eq!(cols.to_vec(), vec![3, 1, 3, 6, 4, 6]);
+        assert_eq!(rows.to_vec(), vec![4, 5, 6]);
+        assert_eq!(
+            t.index_select(0, &Tensor::from(vec![2i64])).err(),
+            Some(TensorError::IndexOutOfRange { index: 2, size: 2 })
+        );
+    }
+
+    #[test]
+    fn gather() {
+        let t = crate::tensor![[1, 2], [3, 4]];
+        let index = crate::tensor![[0i64, 0], [1, 0]];
+
+        assert_eq!(t.gather(1, &index).unwrap().to_vec(), vec![1, 1, 4, 3]);
+        assert_eq!(t.gather(0, &index).unwrap().to_vec(), vec![1, 2, 3, 2]);
+        assert_eq!(
+            t.gather(1, &crate::tensor![[2i64]]).err(),
+            Some(TensorError::IndexOutOfRange { index: 2, size: 2 })
+        );
+    }
+
+    #[test]
+    fn scatter_inverts_gather() {
+        let t = Tensor::<i32>::zeros(&[3, 2]);
+        let index = crate::tensor![[2i64, 0]];
+        let src = crate::tensor![[5, 6], [7, 8]];
+
+        let scattered = t.scatter(0, &index, &src).unwrap();
+
+        assert_eq!(scattered.to_vec(), vec![0, 6, 0, 0, 5, 0]);
+        assert_eq!(t.to_vec(), vec![0; 6]);
+        assert!(t.scatter(0, &crate::tensor![[0i64, 0, 0]], &src).is_err());
+    }
+
+    #[test]
+    fn scatter_add_accumulates_duplicates() {
+        let t = Tensor::from(vec![1, 1, 1]);
+        let index = Tensor::from(vec![0i64, 2, 0, 0]);
+        let src = Tensor::from(vec![10, 20, 30, 40]);
+
+        assert_eq!(
+            t.scatter_add(0, &index, &src).unwrap().to_vec(),
+            vec![81, 1, 21]
+        );
+        assert_eq!(
+            t.scatter(0, &index, &src).unwrap().to_vec(),
+            vec![40, 1, 20]
+        );
+    }
+
+    #[test]
+    fn masked_select_and_masked_fill() {
+        let t = crate::tensor![[1, 2], [3, 4]];
+        let mask = crate::tensor![[true, false], [false, true]];
+        let column_mask = crate::tensor![[false], [true]];
+
+        assert_eq!(t.masked_select(&mask).unwrap().to_vec(), vec![1, 4]);
+        assert_eq!(t.masked_select(&column_mask).unwrap().to_vec(), vec![3, 4]);
+        assert_eq!(t.masked_fill(&mask, 0).unwrap().to_vec(), vec![0, 2, 3, 0]);
+        assert_eq!(t.to_vec(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn nonzero() {
+        let t = crate::tensor![[0, 3], [5, 0]];
+        let mask = Tensor::from(vec![false, true, true]);
+
+        let indices = t.nonzero();
+
+        assert_eq!(indices.shape(), &[2, 2]);
+        assert_eq!(indices.to_vec(), vec![0, 1, 1, 0]);
+        assert_eq!(mask.argwhere().to_vec(), vec![1, 2]);
+        assert_eq!(Tensor::<i32>::zeros(&[2, 2]).nonzero().shape(), &[0, 2]);
+    }
+
+    #[test]
+    fn take_uses_flat_row_major_positions() {
+        let t = crate::tensor![[1, 2, 3], [4, 5, 6]];
+        let transposed = t.transpose(0, 1).unwrap();
+        let indices = crate::tensor![[0i64, 5], [1, 1]];
+
+        assert_eq!(t.take(&indices).unwrap().shape(), &[2, 2]);
+        assert_eq!(t.take(&indices).unwrap().to_vec(), vec![1, 6, 2, 2]);
+        assert_eq!(
+            transposed.take(&indices).unwrap().to_vec(),
+            vec![1, 6, 4, 4]
+        );
+        assert!(t.take(&Tensor::from(vec![6i64])).is_err());
+    }
+
+    #[test]
+    fn index_with_adjacent_index_tensors() {
+        let t = Tensor::arange(0, 24, 1).reshape(&[2, 3, 4]).unwrap();
+        let rows = Tensor::from(vec![0i64, 2]);
+        let cols = Tensor::from(vec![1i64, 3]);
+
+        let picked = t.index(&[None, Some(&rows), Some(&cols)]).unwrap();
+        let whole_rows = t.index(&[None, Some(&rows)]).unwrap();
+
+        assert_eq!(picked.shape(), &[2, 2]);
+        assert_eq!(picked.to_vec(), vec![1, 11, 13, 23]);
+        assert_eq!(whole_rows.shape(), &[2, 2, 4]);
+        assert_eq!(whole_rows.to_vec()[4..8], [8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn index_broadcasts_and_moves_separated_batch_first() {
+        let t = Tensor::arange(0, 24, 1).reshape(&[2, 3, 4]).unwrap();
+        let first = crate::tensor![[0i64], [1]];
+        let last = Tensor::from(vec![0i64, 3]);
+
+        let picked = t.index(&[Some(&first), None, Some(&last)]).unwrap();
+
+        assert_eq!(picked.shape(), &[2, 2, 3]);
+        assert_eq!(
+            picked.to_vec(),
+            vec![0, 4, 8, 3, 7, 11, 12, 16, 20, 15, 19, 23]
+        );
+        assert!(t.index(&[None, None, None, None]).is_err());
+    }
+
+    #[test]
+    fn searchsorted_and_bucketize() {
+        let sorted = Tensor::from(vec![1, 3, 5, 7]);
+        let values = crate::tensor![[3, 6], [9, 0]];
+
+        let left = Tensor::searchsorted(&sorted, &values, false).unwrap();
+        let right = Tensor::searchsorted(&sorted, &values, true).unwrap();
+
+        assert_eq!(left.shape(), &[2, 2]);
+        assert_eq!(left.to_vec(), vec![1, 3, 4, 0]);
+        assert_eq!(right.to_vec(), vec![2, 3, 4, 0]);
+        assert_eq!(
+            Tensor::from(vec![0.5, 5.0])
+                .bucketize(&Tensor::from(vec![1.0, 5.0]), true)
+                .unwrap()
+                .to_vec(),
+            vec![0, 2]
+        );
+        assert!(Tensor::searchsorted(&values, &sorted, false).is_err());
+    }
+
+    #[test]
+    fn where_broadcasts_all_operands() {
+        let cond = crate::tensor![[true], [false]];
+        let a = Tensor::from(vec![1, 2, 3]);
+        let b = Tensor::scalar(0);
+
+        let picked = Tensor::where_(&cond, &a, &b).unwrap();
+
+        assert_eq!(picked.shape(), &[2, 3]);
+        assert_eq!(picked.to_vec(), vec![1, 2, 3, 0, 0, 0]);
+        assert!(Tensor::where_(&cond, &a, &Tensor::from(vec![1, 2])).is_err());
+    }
+}
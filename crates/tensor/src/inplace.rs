@@ -0,0 +1,420 @@
+use crate::error::{Result, TensorError};
+use crate::indexing::check_index;
+use crate::ops::clamp::clamp_value;
+use crate::types::Tensor;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::rc::Rc;
+
+impl<T: Clone> Tensor<T> {
+    /// Sets every element visible through this view to `value`, writing into the shared buffer.
+    pub fn fill_(&self, value: T) {
+        let mut data = self.base.data.borrow_mut();
+        for i in self.base.storage_indices() {
+            data[i] = value.clone();
+        }
+    }
+
+    /// Copies the elements of `src` into this view, writing into the shared buffer.
+    ///
+    /// Both tensors must have the same shape. `src` may alias this tensor's buffer.
+    pub fn copy_from_(&self, src: &Tensor<T>) -> Result<()> {
+        if self.shape() != src.shape() {
+            return Err(TensorError::ShapeMismatch {
+                left: self.shape().to_vec(),
+                right: src.shape().to_vec(),
+            });
+        }
+
+        // Materialize the source first so an aliasing `src` is never borrowed while we write.
+        let values = src.to_vec();
+        let mut data = self.base.data.borrow_mut();
+        for (i, value) in self.base.storage_indices().zip(values) {
+            data[i] = value;
+        }
+        Ok(())
+    }
+
+    /// Sets the elements where `mask` is `true` to `value`, writing into the shared buffer.
+    ///
+    /// `mask` must broadcast to the shape of this tensor.
+    pub fn masked_fill_(&self, mask: &Tensor<bool>, value: T) -> Result<()> {
+        let mask = mask.broadcast_to(self.shape())?.to_vec();
+        let mut data = self.base.data.borrow_mut();
+        for (i, selected) in self.base.storage_indices().zip(mask) {
+            if selected {
+                data[i] = value.clone();
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes `values` to the positions `indices` of this tensor viewed as flattened in
+    /// row-major order, writing into the shared buffer.
+    ///
+    /// `values` must broadcast to the shape of `indices`. When an index repeats, the last
+    /// write wins.
+    pub fn index_put_(&self, indices: &Tensor<i64>, values: &Tensor<T>) -> Result<()> {
+        let values = values.broadcast_to(indices.shape())?.to_vec();
+        let positions = self.base.storage_indices().collect::<Vec<_>>();
+        let targets = indices
+            .to_vec()
+            .into_iter()
+            .map(|i| Ok(positions[check_index(i, positions.len())?]))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut data = self.base.data.borrow_mut();
+        for (target, value) in targets.into_iter().zip(values) {
+            data[target] = value;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Tensor<T> {
+    /// Fails if two logical elements of this view map to the same buffer slot, as in an
+    /// expanded view, where an in-place update would be applied more than once.
+    fn check_no_internal_overlap(&self) -> Result<()> {
+        if self.is_contiguous() || self.numel() == 0 {
+            return Ok(());
+        }
+        let overlap = || TensorError::InternalOverlap {
+            shape: self.shape().to_vec(),
+            strides: self.strides().to_vec(),
+        };
+
+        // Visiting the dimensions from the smallest stride up, the view cannot overlap if each
+        // stride steps past everything the inner dimensions reach. Only layouts that fail
+        // this test need their positions compared one by one.
+        let mut dims = self
+            .shape()
+            .iter()
+            .zip(self.strides())
+            .filter(|&(&size, _)| size > 1)
+            .map(|(&size, &stride)| (stride.unsigned_abs(), size))
+            .collect::<Vec<_>>();
+        dims.sort_unstable();
+        let mut reach = 0;
+        let mut dominated = true;
+        for (stride, size) in dims {
+            if stride == 0 {
+                return Err(overlap());
+            }
+            dominated &= stride > reach;
+            reach += stride * (size - 1);
+        }
+        if dominated {
+            return Ok(());
+        }
+
+        let mut positions = self.base.storage_indices().collect::<Vec<_>>();
+        positions.sort_unstable();
+        if positions.windows(2).any(|pair| pair[0] == pair[1]) {
+            return Err(overlap());
+        }
+        Ok(())
+    }
+}
+
+impl<T: Clone> Tensor<T> {
+    /// Replaces every element `x` of this view with `f(x)`, in logical row-major order,
+    /// writing into the shared buffer. See [`Tensor::map`] for the allocating version.
+    pub fn map_inplace(&self, mut f: impl FnMut(T) -> T) -> Result<()> {
+        self.check_no_internal_overlap()?;
+        let mut data = self.base.data.borrow_mut();
+        for i in self.base.storage_indices() {
+            data[i] = f(data[i].clone());
+        }
+        Ok(())
+    }
+}
+
+impl<T: Copy> Tensor<T> {
+    /// Replaces each element `x` of this view with `f(x, r)`, where `r` is the matching
+    /// element of `rhs` broadcast to this tensor's shape, writing into the shared buffer.
+    fn zip_apply_(&self, rhs: &Tensor<T>, mut f: impl FnMut(T, T) -> T) -> Result<()> {
+        self.check_no_internal_overlap()?;
+        let rhs = rhs.broadcast_to(self.shape())?;
+
+        if Rc::ptr_eq(&self.base.data, &rhs.base.data) {
+            // Read the whole right-hand side before writing so earlier writes cannot leak
+            // into later reads.
+            let values = rhs.to_vec();
+            let mut data = self.base.data.borrow_mut();
+            for (i, value) in self.base.storage_indices().zip(values) {
+                data[i] = f(data[i], value);
+            }
+        } else {
+            let mut data = self.base.data.borrow_mut();
+            let rhs_data = rhs.base.data.borrow();
+            for (i, r) in self.base.storage_indices().zip(rhs.base.storage_indices()) {
+                data[i] = f(data[i], rhs_data[r]);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: PartialOrd + Copy> Tensor<T> {
+    /// Limits every element of this view to `[min, max]`, writing into the shared buffer.
+    /// Either bound may be `None`.
+    pub fn clamp_(&self, min: Option<T>, max: Option<T>) {
+        let mut data = self.base.data.borrow_mut();
+        for i in self.base.storage_indices() {
+            data[i] = clamp_value(data[i], min, max);
+        }
+    }
+
+    /// In-place [`Tensor::clamp_min`].
+    pub fn clamp_min_(&self, min: T) {
+        self.clamp_(Some(min), None);
+    }
+
+    /// In-place [`Tensor::clamp_max`].
+    pub fn clamp_max_(&self, max: T) {
+        self.clamp_(None, Some(max));
+    }
+}
+
+impl<T: Add<Output = T> + Copy> Tensor<T> {
+    /// Adds `rhs`, broadcast to this tensor's shape, into this view's buffer.
+    pub fn add_(&self, rhs: &Tensor<T>) -> Result<()> {
+        self.zip_apply_(rhs, |left, right| left + right)
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> Tensor<T> {
+    /// Subtracts `rhs`, broadcast to this tensor's shape, in this view's buffer.
+    pub fn sub_(&self, rhs: &Tensor<T>) -> Result<()> {
+        self.zip_apply_(rhs, |left, right| left - right)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Tensor<T> {
+    /// Multiplies this view's buffer by `rhs`, broadcast to this tensor's shape.
+    pub fn mul_(&self, rhs: &Tensor<T>) -> Result<()> {
+        self.zip_apply_(rhs, |left, right| left * right)
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Tensor<T> {
+    /// Divides this view's buffer by `rhs`, broadcast to this tensor's shape.
+    pub fn div_(&self, rhs: &Tensor<T>) -> Result<()> {
+        self.zip_apply_(rhs, |left, right| left / right)
+    }
+}
+
+/// Implements `tensor op= &rhs` and `tensor op= rhs` on top of an in-place method. The
+/// right-hand side is broadcast to the left's shape, and the write goes through to every
+/// handle sharing the buffer. Panics where the in-place method would return an error.
+macro_rules! impl_assign_op {
+    ($trait:ident, $method:ident, $op:ident, $inplace:ident, $what:literal) => {
+        impl<T: $op<Output = T> + Copy> $trait<&Tensor<T>> for Tensor<T> {
+            fn $method(&mut self, rhs: &Tensor<T>) {
+                self.$inplace(rhs)
+                    .unwrap_or_else(|e| panic!(concat!("Tensor ", $what, " failed: {}"), e));
+                self.requires_grad = self.requires_grad || rhs.requires_grad;
+            }
+        }
+
+        impl<T: $op<Output = T> + Copy> $trait<Tensor<T>> for Tensor<T> {
+            fn $method(&mut self, rhs: Tensor<T>) {
+                $trait::$method(self, &rhs);
+            }
+        }
+    };
+}
+
+impl_assign_op!(AddAssign, add_assign, Add, add_, "addition");
+impl_assign_op!(SubAssign, sub_assign, Sub, sub_, "subtraction");
+impl_assign_op!(MulAssign, mul_assign, Mul, mul_, "multiplication");
+impl_assign_op!(DivAssign, div_assign, Div, div_, "division");
+
+#[cfg(test)]
+mod tests {
+    use crate::builder::TensorBuilder;
+    use crate::error::TensorError;
+    use crate::types::{BaseTensor, Tensor};
+
+    #[test]
+    fn fill_writes_through_view() {
+        let t = Tensor::from_vec(vec![0; 6], &[2, 3]);
+        let column = Tensor::from(BaseTensor {
+            shape: vec![2].into(),
+            strides: vec![3],
+            offset: 1,
+            ..t.base.share()
+        });
+
+        column.fill_(9);
+
+        assert_eq!(t.to_vec(), vec![0, 9, 0, 0, 9, 0]);
+    }
+
+    #[test]
+    fn copy_from_() {
+        let dst = Tensor::<f32>::zeros(&[2, 2]);
+        let src = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0], &[2, 2]);
+
+        dst.copy_from_(&src).unwrap();
+
+        assert_eq!(dst.to_vec(), src.to_vec());
+        assert_eq!(
+            dst.copy_from_(&Tensor::zeros(&[4])),
+            Err(TensorError::ShapeMismatch {
+                left: vec![2, 2],
+                right: vec![4],
+            })
+        );
+    }
+
+    #[test]
+    fn copy_from_aliasing_source() {
+        let t = Tensor::from_vec(vec![1, 2, 3, 4], &[2, 2]);
+        let transposed = Tensor::from(BaseTensor {
+            strides: vec![1, 2],
+            ..t.base.share()
+        });
+
+        t.copy_from_(&transposed).unwrap();
+
+        assert_eq!(t.to_vec(), vec![1, 3, 2, 4]);
+    }
+
+    #[test]
+    fn masked_fill_broadcasts_mask() {
+        let t = Tensor::from_vec(vec![1, 2, 3, 4, 5, 6], &[2, 3]);
+        let mask = Tensor::from(vec![true, false, true]);
+
+        t.masked_fill_(&mask, 0).unwrap();
+
+        assert_eq!(t.to_vec(), vec![0, 2, 0, 0, 5, 0]);
+        assert!(t.masked_fill_(&Tensor::from(vec![true, false]), 0).is_err());
+    }
+
+    #[test]
+    fn index_put_writes_flat_positions() {
+        let t = Tensor::from_vec(vec![0; 6], &[2, 3]);
+        let transposed = t.transpose(0, 1).unwrap();
+
+        transposed
+            .index_put_(&Tensor::from(vec![1i64, 4]), &Tensor::from(vec![7, 8]))
+            .unwrap();
+        t.index_put_(&Tensor::from(vec![5i64]), &Tensor::scalar(9))
+            .unwrap();
+
+        assert_eq!(t.to_vec(), vec![0, 0, 8, 7, 0, 9]);
+        assert_eq!(
+            t.index_put_(&Tensor::from(vec![6i64]), &Tensor::scalar(1)),
+            Err(TensorError::IndexOutOfRange { index: 6, size: 6 })
+        );
+    }
+
+    #[test]
+    fn arithmetic_in_place_broadcasts_rhs() {
+        let t = Tensor::from_vec(vec![1.0, 2.0, 3.0, 4.0], &[2, 2]);
+        let row = Tensor::from(vec![1.0, 2.0]);
+
+        t.add_(&row).unwrap();
+        assert_eq!(t.to_vec(), vec![2.0, 4.0, 4.0, 6.0]);
+        t.mul_(&row).unwrap();
+        assert_eq!(t.to_vec(), vec![2.0, 8.0, 4.0, 12.0]);
+        t.div_(&Tensor::scalar(2.0)).unwrap();
+        t.sub_(&row).unwrap();
+        assert_eq!(t.to_vec(), vec![0.0, 2.0, 1.0, 4.0]);
+        assert!(row.add_(&t).is_err());
+    }
+
+    #[test]
+    fn arithmetic_in_place_handles_aliasing() {
+        let t = Tensor::from_vec(vec![1, 2, 3, 4], &[2, 2]);
+        let transposed = t.transpose(0, 1).unwrap();
+
+        t.add_(&transposed).unwrap();
+
+        assert_eq!(t.to_vec(), vec![2, 5, 5, 8]);
+        assert_eq!(
+            t.expand(&[2, 2, 2]).unwrap().add_(&Tensor::scalar(1)),
+            Err(TensorError::InternalOverlap {
+                shape: vec![2, 2, 2],
+                strides: vec![0, 2, 1],
+            })
+        );
+    }
+
+    #[test]
+    fn overlap_check_on_strided_views() {
+        let t = Tensor::from_vec((0..6).collect(), &[2, 3]);
+        let flipped = t.flip(&[1]).unwrap().transpose(0, 1).unwrap();
+        // Strides that do not dominate each other but still address distinct slots.
+        let interleaved = TensorBuilder::new()
+            .data((0..8).collect())
+            .shape(&[2, 3])
+            .strides(&[3, 2])
+            .build()
+            .unwrap();
+        let overlapping = TensorBuilder::new()
+            .data(vec![0; 3])
+            .shape(&[2, 2])
+            .strides(&[1, 1])
+            .build()
+            .unwrap();
+
+        flipped.add_(&Tensor::scalar(10)).unwrap();
+        interleaved.mul_(&Tensor::scalar(2)).unwrap();
+
+        assert_eq!(t.to_vec(), vec![10, 11, 12, 13, 14, 15]);
+        assert_eq!(interleaved.to_vec(), vec![0, 4, 8, 6, 10, 14]);
+        assert!(matches!(
+            overlapping.map_inplace(|x| x + 1),
+            Err(TensorError::InternalOverlap { .. })
+        ));
+    }
+
+    #[test]
+    fn assign_operators() {
+        let mut grad = Tensor::from_vec(vec![1, 2, 3, 4], &[2, 2]);
+        let alias = grad.share();
+
+        grad += Tensor::from(vec![10, 20]);
+        grad -= &Tensor::scalar(1);
+        grad *= Tensor::scalar(2);
+        grad /= Tensor::from_vec(vec![1, 2], &[2, 1]);
+
+        assert_eq!(alias.to_vec(), vec![20, 42, 12, 23]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be broadcast")]
+    fn assign_operator_rejects_larger_rhs() {
+        let mut t = Tensor::from(vec![1, 2]);
+        t += Tensor::from_vec(vec![1, 2, 3, 4], &[2, 2]);
+    }
+
+    #[test]
+    fn clamp_in_place() {
+        let t = Tensor::from_vec(vec![-1, 5, 10, 2], &[2, 2]);
+        let column = t.narrow(1, 1, 1).unwrap();
+
+        column.clamp_max_(4);
+        t.clamp_min_(0);
+
+        assert_eq!(t.to_vec(), vec![0, 4, 10, 2]);
+    }
+
+    #[test]
+    fn map_inplace_writes_through_view() {
+        let t = Tensor::from_vec(vec![1, 2, 3, 4], &[2, 2]);
+        let column = t.narrow(1, 0, 1).unwrap();
+
+        column.map_inplace(|x| x * 10).unwrap();
+
+        assert_eq!(t.to_vec(), vec![10, 2, 30, 4]);
+        assert!(
+            Tensor::scalar(1)
+                .expand(&[3])
+                .unwrap()
+                .map_inplace(|x| x + 1)
+                .is_err()
+        );
+    }
+}
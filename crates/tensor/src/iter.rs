@@ -0,0 +1,240 @@
+use crate::error::{Result, TensorError};
+use crate::types::{StorageIndices, Tensor};
+use crate::view::check_dim;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Iterator over the elements of a tensor in logical row-major order, created by
+/// [`Tensor::iter`]. Elements are cloned out of the buffer one at a time.
+pub struct Iter<T> {
+    data: Rc<RefCell<Vec<T>>>,
+    positions: StorageIndices,
+}
+
+impl<T: Clone> Iterator for Iter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let position = self.positions.next()?;
+        Some(self.data.borrow()[position].clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for Iter<T> {}
+
+/// Iterator over `(multi_index, element)` pairs in logical row-major order, created by
+/// [`Tensor::indexed_iter`].
+pub struct IndexedIter<T> {
+    elements: Iter<T>,
+    shape: Vec<usize>,
+    index: Vec<usize>,
+}
+
+impl<T: Clone> Iterator for IndexedIter<T> {
+    type Item = (Vec<usize>, T);
+
+    fn next(&mut self) -> Option<(Vec<usize>, T)> {
+        let value = self.elements.next()?;
+        let index = self.index.clone();
+        // Advance the multi-index like an odometer for the next element.
+        for dim in (0..self.shape.len()).rev() {
+            self.index[dim] += 1;
+            if self.index[dim] < self.shape[dim] {
+                break;
+            }
+            self.index[dim] = 0;
+        }
+        Some((index, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for IndexedIter<T> {}
+
+/// Iterator over the sub-tensors of a tensor along one dimension, created by
+/// [`Tensor::iter_axis`]. Each item is a view with that dimension removed.
+pub struct AxisIter<T> {
+    source: Tensor<T>,
+    dim: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<T> AxisIter<T> {
+    fn view_at(&self, position: usize) -> Tensor<T> {
+        let mut view = self.source.base.share();
+        let stride = view.strides.remove(self.dim);
+        view.shape.remove(self.dim);
+        view.offset = (view.offset as isize + position as isize * stride) as usize;
+        view.into_tensor(self.source.requires_grad)
+    }
+}
+
+impl<T> Iterator for AxisIter<T> {
+    type Item = Tensor<T>;
+
+    fn next(&mut self) -> Option<Tensor<T>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.view_at(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for AxisIter<T> {
+    fn next_back(&mut self) -> Option<Tensor<T>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.view_at(self.back))
+    }
+}
+
+impl<T> ExactSizeIterator for AxisIter<T> {}
+
+impl<T> Tensor<T> {
+    /// Iterates over the elements in logical row-major order, whatever the strides and
+    /// offset. Use [`Tensor::to_vec`] to collect them in one go.
+    pub fn iter(&self) -> Iter<T> {
+        Iter {
+            data: Rc::clone(&self.base.data),
+            positions: self.base.storage_indices(),
+        }
+    }
+
+    /// Like [`Tensor::iter`], but pairs each element with its multi-index.
+    pub fn indexed_iter(&self) -> IndexedIter<T> {
+        IndexedIter {
+            elements: self.iter(),
+            shape: self.shape().to_vec(),
+            index: vec![0; self.ndim()],
+        }
+    }
+
+    /// Iterates over the views obtained by fixing each index of `dim` in turn. The views
+    /// share this tensor's buffer and have one dimension fewer.
+    pub fn iter_axis(&self, dim: usize) -> Result<AxisIter<T>> {
+        check_dim(dim, self.ndim())?;
+        Ok(AxisIter {
+            source: self.share(),
+            dim,
+            front: 0,
+            back: self.shape()[dim],
+        })
+    }
+
+    /// Iterates over the rows of a 2-D tensor.
+    pub fn rows(&self) -> Result<AxisIter<T>> {
+        self.check_matrix()?;
+        self.iter_axis(0)
+    }
+
+    /// Iterates over the columns of a 2-D tensor.
+    pub fn cols(&self) -> Result<AxisIter<T>> {
+        self.check_matrix()?;
+        self.iter_axis(1)
+    }
+
+    fn check_matrix(&self) -> Result<()> {
+        if self.ndim() != 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: self.ndim(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    #[test]
+    fn rows_and_cols() {
+        let t = crate::tensor![[1, 2, 3], [4, 5, 6]];
+
+        let rows = t.rows().unwrap().map(|r| r.to_vec()).collect::<Vec<_>>();
+        let cols = t
+            .cols()
+            .unwrap()
+            .rev()
+            .map(|c| c.to_vec())
+            .collect::<Vec<_>>();
+
+        assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(cols, vec![vec![3, 6], vec![2, 5], vec![1, 4]]);
+        assert_eq!(
+            Tensor::from(vec![1, 2]).rows().err(),
+            Some(TensorError::RankMismatch {
+                expected: 2,
+                actual: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn iter_axis_yields_shared_views() {
+        let t = Tensor::from_vec(vec![0; 8], &[2, 2, 2]);
+
+        let mut slices = t.iter_axis(2).unwrap();
+        assert_eq!(slices.len(), 2);
+        let last = slices.next_back().unwrap();
+        last.fill_(1);
+
+        assert_eq!(last.shape(), &[2, 2]);
+        assert_eq!(t.to_vec(), vec![0, 1, 0, 1, 0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn iter_follows_logical_order() {
+        let t = crate::tensor![[1, 2, 3], [4, 5, 6]];
+        let transposed = t.transpose(0, 1).unwrap();
+
+        assert_eq!(
+            transposed.iter().collect::<Vec<_>>(),
+            vec![1, 4, 2, 5, 3, 6]
+        );
+        assert_eq!(transposed.iter().len(), 6);
+        assert_eq!(
+            transposed.flip(&[0]).unwrap().iter().collect::<Vec<_>>(),
+            vec![3, 6, 2, 5, 1, 4]
+        );
+    }
+
+    #[test]
+    fn indexed_iter() {
+        let t = crate::tensor![[1, 2], [3, 4]].transpose(0, 1).unwrap();
+
+        let pairs = t.indexed_iter().collect::<Vec<_>>();
+
+        assert_eq!(
+            pairs,
+            vec![
+                (vec![0, 0], 1),
+                (vec![0, 1], 3),
+                (vec![1, 0], 2),
+                (vec![1, 1], 4),
+            ]
+        );
+        assert_eq!(
+            Tensor::scalar(7).indexed_iter().collect::<Vec<_>>(),
+            vec![(vec![], 7)]
+        );
+    }
+}
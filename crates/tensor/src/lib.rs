@@ -1 +1,18 @@
+pub mod builder;
+pub mod convert;
+pub mod creation;
+pub mod error;
+pub mod indexing;
+pub mod inplace;
+pub mod iter;
+pub mod linalg;
+#[doc(hidden)]
+pub mod macros;
+pub mod manipulation;
+pub mod ops;
+pub mod random;
+pub mod reduce;
+pub mod shape;
+pub mod sort;
 pub mod types;
+pub mod view;
@@ -0,0 +1,116 @@
+use super::lu::substitute;
+use super::{Matrix, check_square, right_hand_sides, solution, stack, unstack};
+use crate::error::{Result, TensorError};
+use crate::types::Tensor;
+use num_traits::Float;
+
+/// Cholesky factor `L` of a symmetric positive-definite matrix, reading only its lower
+/// triangle.
+fn cholesky_factor<T: Float>(a: &Matrix<T>) -> Result<Matrix<T>> {
+    let n = a.rows;
+    let mut l = Matrix::zeros(n, n);
+    for j in 0..n {
+        let diagonal = (0..j).fold(a[(j, j)], |acc, k| acc - l[(j, k)] * l[(j, k)]);
+        // `!(x > 0)` also rejects a NaN pivot.
+        if diagonal.partial_cmp(&T::zero()) != Some(std::cmp::Ordering::Greater) {
+            return Err(TensorError::NotPositiveDefinite { order: j + 1 });
+        }
+        l[(j, j)] = diagonal.sqrt();
+        for i in j + 1..n {
+            let value = (0..j).fold(a[(i, j)], |acc, k| acc - l[(i, k)] * l[(j, k)]);
+            l[(i, j)] = value / l[(j, j)];
+        }
+    }
+    Ok(l)
+}
+
+impl<T: Float> Tensor<T> {
+    /// Lower-triangular `L` with positive diagonal such that `A = L L^T`, for each symmetric
+    /// positive-definite matrix in the last two dimensions. Only the lower triangle of `A` is
+    /// read.
+    ///
+    /// Fails with [`TensorError::NotPositiveDefinite`] naming the first leading minor that is
+    /// not positive.
+    pub fn cholesky(&self) -> Result<Tensor<T>> {
+        let n = check_square(self)?;
+        let (batch, matrices) = unstack(self);
+        let factors = matrices
+            .iter()
+            .map(cholesky_factor)
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut result = stack(&batch, n, n, factors);
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+}
+
+/// Solves `A x = b` given the Cholesky factor `l` of `A` from [`Tensor::cholesky`], by one
+/// forward and one backward substitution. Shapes follow [`solve`](super::solve).
+pub fn cholesky_solve<T: Float>(l: &Tensor<T>, b: &Tensor<T>) -> Result<Tensor<T>> {
+    let n = check_square(l)?;
+    let columns = right_hand_sides(l, b, n)?;
+    let (_, factors) = unstack(l);
+    let (_, rhs) = unstack(&columns);
+
+    let solutions = factors
+        .iter()
+        .zip(rhs)
+        .map(|(l, mut x)| {
+            substitute(l, &mut x, false, false);
+            substitute(&l.transpose(), &mut x, true, false);
+            x
+        })
+        .collect();
+    solution(l, b, &columns, solutions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cholesky_solve;
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    fn assert_close(actual: &Tensor<f64>, expected: &[f64]) {
+        for (a, e) in actual.to_vec().iter().zip(expected) {
+            assert!((a - e).abs() < 1e-10, "{a} != {e}");
+        }
+    }
+
+    #[test]
+    fn cholesky_and_solve() {
+        let a = crate::tensor![
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0]
+        ];
+
+        let l = a.cholesky().unwrap();
+        let x = cholesky_solve(&l, &Tensor::from(vec![-20.0, -43.0, 192.0])).unwrap();
+
+        assert_close(&l, &[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]);
+        assert_close(&x, &[1.0, 2.0, 3.0]);
+        assert_eq!(
+            Tensor::stack(&[&a, &a], 0)
+                .unwrap()
+                .cholesky()
+                .unwrap()
+                .shape(),
+            &[2, 3, 3]
+        );
+    }
+
+    #[test]
+    fn cholesky_rejects_indefinite() {
+        let indefinite = crate::tensor![[1.0, 2.0], [2.0, 1.0]];
+
+        assert_eq!(
+            indefinite.cholesky().err(),
+            Some(TensorError::NotPositiveDefinite { order: 2 })
+        );
+        assert_eq!(
+            crate::tensor![[-1.0]].cholesky().err(),
+            Some(TensorError::NotPositiveDefinite { order: 1 })
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use super::svd::MAX_SWEEPS;
+use super::{Matrix, check_square, stack, unstack};
+use crate::error::Result;
+use crate::types::Tensor;
+use num_traits::Float;
+
+/// Eigenvalues in ascending order and the matching unit eigenvectors, as columns, of a
+/// symmetric matrix by cyclic Jacobi rotations. Only the lower triangle of `a` is read.
+pub(crate) fn jacobi_eigh<T: Float>(a: &Matrix<T>) -> (Vec<T>, Matrix<T>) {
+    let n = a.rows;
+    let mut a = a.clone();
+    for i in 0..n {
+        for j in i + 1..n {
+            a[(i, j)] = a[(j, i)];
+        }
+    }
+    let mut v = Matrix::identity(n);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (alpha, beta, gamma) = (a[(p, p)], a[(q, q)], a[(p, q)]);
+                if gamma.abs() <= T::epsilon() * (alpha * beta).abs().sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                // The rotation `J` for which `J^T A J` has a zero at `(p, q)`.
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = (T::one() + t * t).sqrt().recip();
+                let s = c * t;
+                for w in [&mut a, &mut v] {
+                    for k in 0..n {
+                        let (x, y) = (w[(k, p)], w[(k, q)]);
+                        w[(k, p)] = c * x - s * y;
+                        w[(k, q)] = s * x + c * y;
+                    }
+                }
+                for k in 0..n {
+                    let (x, y) = (a[(p, k)], a[(q, k)]);
+                    a[(p, k)] = c * x - s * y;
+                    a[(q, k)] = s * x + c * y;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| {
+        a[(i, i)]
+            .partial_cmp(&a[(j, j)])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let mut vectors = Matrix::zeros(n, n);
+    for (to, &from) in order.iter().enumerate() {
+        for k in 0..n {
+            vectors[(k, to)] = v[(k, from)];
+        }
+    }
+    (order.iter().map(|&i| a[(i, i)]).collect(), vectors)
+}
+
+impl<T: Float> Tensor<T> {
+    /// Eigendecomposition `A = V diag(w) V^T` of each symmetric matrix in the last two
+    /// dimensions, returned as `(w, V)`. The eigenvalues `w` are in ascending order and the
+    /// columns of `V` are the matching orthonormal eigenvectors. Only the lower triangle of
+    /// `A` is read.
+    pub fn eigh(&self) -> Result<(Tensor<T>, Tensor<T>)> {
+        let n = check_square(self)?;
+        let (batch, matrices) = unstack(self);
+
+        let (mut values, mut vectors) = (Vec::new(), Vec::new());
+        for m in &matrices {
+            let (w, v) = jacobi_eigh(m);
+            values.extend(w);
+            vectors.push(v);
+        }
+
+        let mut shape = batch.clone();
+        shape.push(n);
+        let mut w = Tensor::from_vec(values, &shape);
+        let mut v = stack(&batch, n, n, vectors);
+        w.requires_grad = self.requires_grad;
+        v.requires_grad = self.requires_grad;
+        Ok((w, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    fn assert_close(actual: &Tensor<f64>, expected: &Tensor<f64>) {
+        assert_eq!(actual.shape(), expected.shape());
+        for (a, e) in actual.to_vec().iter().zip(expected.to_vec()) {
+            assert!((a - e).abs() < 1e-10, "{a} != {e}");
+        }
+    }
+
+    #[test]
+    fn eigh_reconstructs() {
+        let a = crate::tensor![[4.0, 1.0, -2.0], [1.0, 2.0, 0.0], [-2.0, 0.0, 3.0]];
+        // The upper triangle is ignored.
+        let lower = a.tril(0).unwrap();
+
+        let (w, v) = lower.eigh().unwrap();
+
+        let values = w.to_vec();
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_close(
+            &v.matmul(&Tensor::diag(&values))
+                .matmul(&v.transpose(0, 1).unwrap()),
+            &a,
+        );
+        assert_close(&v.transpose(0, 1).unwrap().matmul(&v), &Tensor::eye(3));
+    }
+
+    #[test]
+    fn eigh_batched_and_errors() {
+        let a = crate::tensor![[2.0, 1.0], [1.0, 2.0]];
+        let batched = Tensor::stack(&[&a, &Tensor::eye(2)], 0).unwrap();
+
+        let (w, v) = batched.eigh().unwrap();
+
+        assert_close(&w, &crate::tensor![[1.0, 3.0], [1.0, 1.0]]);
+        assert_eq!(v.shape(), &[2, 2, 2]);
+        assert!(matches!(
+            Tensor::<f64>::zeros(&[2, 3]).eigh(),
+            Err(TensorError::NotSquare { .. })
+        ));
+    }
+}
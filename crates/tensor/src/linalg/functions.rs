@@ -0,0 +1,199 @@
+use super::lu::lu_factor;
+use super::{Matrix, check_square, stack, unstack};
+use crate::error::Result;
+use crate::types::Tensor;
+use num_traits::Float;
+
+/// Coefficients of the degree-13 Padé approximant of `exp`, constant term first.
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+/// Largest 1-norm for which the degree-13 approximant is accurate to double precision
+/// (Higham, 2005); larger matrices are scaled down by a power of two first.
+const THETA_13: f64 = 5.371920351148152;
+
+/// `a^n` for `n >= 0` by repeated squaring.
+fn power<T: Float>(a: &Matrix<T>, mut n: u64) -> Matrix<T> {
+    let mut result = Matrix::identity(a.rows);
+    let mut base = a.clone();
+    while n > 0 {
+        if n & 1 == 1 {
+            result = result.matmul(&base);
+        }
+        n >>= 1;
+        if n > 0 {
+            base = base.matmul(&base);
+        }
+    }
+    result
+}
+
+/// Linear combination `sum(c_i * m_i)` of equally sized matrices.
+fn combine<T: Float>(terms: &[(f64, &Matrix<T>)]) -> Matrix<T> {
+    let (rows, cols) = (terms[0].1.rows, terms[0].1.cols);
+    let mut result = Matrix::zeros(rows, cols);
+    for &(c, m) in terms {
+        let c = T::from(c).unwrap();
+        for (x, &y) in result.data.iter_mut().zip(&m.data) {
+            *x = *x + c * y;
+        }
+    }
+    result
+}
+
+/// `exp(a)` by scaling and squaring with a degree-13 Padé approximant.
+fn exponential<T: Float>(a: &Matrix<T>) -> Matrix<T> {
+    let n = a.rows;
+    let norm = (0..n)
+        .map(|j| (0..n).fold(T::zero(), |acc, i| acc + a[(i, j)].abs()))
+        .fold(T::zero(), T::max);
+    let squarings = (norm / T::from(THETA_13).unwrap())
+        .log2()
+        .ceil()
+        .to_i32()
+        .unwrap_or(0)
+        .max(0);
+    let scale = T::from(2.0).unwrap().powi(-squarings);
+    let a = Matrix {
+        rows: n,
+        cols: n,
+        data: a.data.iter().map(|&x| x * scale).collect(),
+    };
+
+    let b = PADE_13;
+    let identity = Matrix::identity(n);
+    let a2 = a.matmul(&a);
+    let a4 = a2.matmul(&a2);
+    let a6 = a4.matmul(&a2);
+    let odd = a6.matmul(&combine(&[(b[13], &a6), (b[11], &a4), (b[9], &a2)]));
+    let odd = combine(&[
+        (1.0, &odd),
+        (b[7], &a6),
+        (b[5], &a4),
+        (b[3], &a2),
+        (b[1], &identity),
+    ]);
+    let u = a.matmul(&odd);
+    let even = a6.matmul(&combine(&[(b[12], &a6), (b[10], &a4), (b[8], &a2)]));
+    let v = combine(&[
+        (1.0, &even),
+        (b[6], &a6),
+        (b[4], &a4),
+        (b[2], &a2),
+        (b[0], &identity),
+    ]);
+
+    // The approximant is `(V - U)^-1 (V + U)`.
+    let mut result =
+        lu_factor(&combine(&[(1.0, &v), (-1.0, &u)])).solve(&combine(&[(1.0, &v), (1.0, &u)]));
+    for _ in 0..squarings {
+        result = result.matmul(&result);
+    }
+    result
+}
+
+impl<T: Float> Tensor<T> {
+    /// Raises each square matrix in the last two dimensions to the integer power `n` by
+    /// repeated squaring. `n = 0` gives identity matrices and a negative `n` powers the
+    /// inverse, which fails with
+    /// [`SingularMatrix`](crate::error::TensorError::SingularMatrix) if some matrix is
+    /// singular.
+    pub fn matrix_power(&self, n: i64) -> Result<Tensor<T>> {
+        let order = check_square(self)?;
+        let base = if n < 0 { self.inverse()? } else { self.share() };
+        let (batch, matrices) = unstack(&base);
+        let powers = matrices
+            .iter()
+            .map(|m| power(m, n.unsigned_abs()))
+            .collect();
+
+        let mut result = stack(&batch, order, order, powers);
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+
+    /// Matrix exponential `sum(A^k / k!)` of each square matrix in the last two dimensions,
+    /// by scaling and squaring with a degree-13 Padé approximant.
+    pub fn matrix_exp(&self) -> Result<Tensor<T>> {
+        let n = check_square(self)?;
+        let (batch, matrices) = unstack(self);
+        let exponentials = matrices.iter().map(exponential).collect();
+
+        let mut result = stack(&batch, n, n, exponentials);
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    fn assert_close(actual: &Tensor<f64>, expected: &Tensor<f64>) {
+        assert_eq!(actual.shape(), expected.shape());
+        for (a, e) in actual.to_vec().iter().zip(expected.to_vec()) {
+            assert!((a - e).abs() <= 1e-10 * e.abs().max(1.0), "{a} != {e}");
+        }
+    }
+
+    #[test]
+    fn matrix_power() {
+        let fibonacci = crate::tensor![[1.0, 1.0], [1.0, 0.0]];
+
+        assert_close(
+            &fibonacci.matrix_power(10).unwrap(),
+            &crate::tensor![[89.0, 55.0], [55.0, 34.0]],
+        );
+        assert_close(&fibonacci.matrix_power(0).unwrap(), &Tensor::eye(2));
+        assert_close(
+            &fibonacci
+                .matrix_power(-2)
+                .unwrap()
+                .matmul(&fibonacci.matrix_power(2).unwrap()),
+            &Tensor::eye(2),
+        );
+        assert!(matches!(
+            crate::tensor![[1.0, 2.0], [2.0, 4.0]].matrix_power(-1),
+            Err(TensorError::SingularMatrix)
+        ));
+    }
+
+    #[test]
+    fn matrix_exp() {
+        let (t, e) = (3.0f64, std::f64::consts::E);
+        // The generator of plane rotations, a diagonal matrix, and one large enough to
+        // need scaling.
+        let rotation = crate::tensor![[0.0, -t], [t, 0.0]];
+        let diagonal = crate::tensor![[1.0, 0.0], [0.0, -2.0]];
+        let large = crate::tensor![[20.0, 0.0], [0.0, 0.0]];
+        let batched = Tensor::stack(&[&rotation, &diagonal, &large], 0).unwrap();
+
+        let result = batched.matrix_exp().unwrap();
+
+        let expected = crate::tensor![
+            [[t.cos(), -t.sin()], [t.sin(), t.cos()]],
+            [[e, 0.0], [0.0, e.powi(-2)]],
+            [[20.0f64.exp(), 0.0], [0.0, 1.0]]
+        ];
+        assert_close(&result, &expected);
+        assert_close(
+            &Tensor::<f64>::zeros(&[3, 3]).matrix_exp().unwrap(),
+            &Tensor::eye(3),
+        );
+    }
+}
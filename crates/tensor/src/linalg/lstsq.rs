@@ -0,0 +1,147 @@
+use super::svd::jacobi_svd;
+use super::{Matrix, check_matrix, right_hand_sides, solution, unstack};
+use crate::error::Result;
+use crate::types::Tensor;
+use num_traits::Float;
+
+/// The least-squares solution of `a x = b`, as returned by [`lstsq`].
+pub struct Lstsq<T> {
+    /// The minimum-norm `x` minimizing `||a x - b||`, shaped like `b` with `n` rows.
+    pub solution: Tensor<T>,
+    /// The squared residual norm of each column of `b`, shaped like `b` without its row
+    /// dimension. Only meaningful for overdetermined systems of full rank, so it is empty,
+    /// of shape `[0]`, otherwise.
+    pub residuals: Tensor<T>,
+    /// The effective rank of each matrix of `a`, shaped like its batch dimensions.
+    pub rank: Tensor<i64>,
+    /// The singular values of each matrix of `a` in descending order.
+    pub singular_values: Tensor<T>,
+}
+
+/// Solves `a x = b` in the least-squares sense through the singular value decomposition of
+/// `a`, so overdetermined, underdetermined and rank-deficient systems are all handled.
+///
+/// `a` is `[..., m, n]` and `b` is either `[..., m, k]` with the same batch dimensions or, for
+/// a single matrix, a vector `[m]`. Singular values at most `max(m, n) * epsilon` times the
+/// largest one count as zero when determining the rank.
+pub fn lstsq<T: Float>(a: &Tensor<T>, b: &Tensor<T>) -> Result<Lstsq<T>> {
+    check_matrix(a)?;
+    let (m, n) = (a.shape()[a.ndim() - 2], a.shape()[a.ndim() - 1]);
+    let columns = right_hand_sides(a, b, m)?;
+    let k = columns.shape()[columns.ndim() - 1];
+    let (batch, matrices) = unstack(a);
+    let (_, rhs) = unstack(&columns);
+    let rcond = T::epsilon() * T::from(m.max(n)).unwrap();
+
+    let (mut solutions, mut residuals, mut ranks, mut values) =
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    for (a, b) in matrices.iter().zip(&rhs) {
+        let (u, s, v) = jacobi_svd(a);
+        let cutoff = rcond * s.first().copied().unwrap_or(T::zero());
+        let rank = s.iter().take_while(|&&x| x > cutoff).count();
+
+        let mut x = Matrix::zeros(n, k);
+        for (r, &value) in s.iter().enumerate().take(rank) {
+            for col in 0..k {
+                let projection = (0..m).fold(T::zero(), |acc, i| acc + u[(i, r)] * b[(i, col)]);
+                let scaled = projection / value;
+                for i in 0..n {
+                    x[(i, col)] = x[(i, col)] + scaled * v[(i, r)];
+                }
+            }
+        }
+        for col in 0..k {
+            residuals.push((0..m).fold(T::zero(), |acc, i| {
+                let fitted = (0..n).fold(T::zero(), |acc, j| acc + a[(i, j)] * x[(j, col)]);
+                let error = fitted - b[(i, col)];
+                acc + error * error
+            }));
+        }
+        solutions.push(x);
+        ranks.push(rank as i64);
+        values.extend(s);
+    }
+
+    let mut residual_shape = batch.clone();
+    if b.ndim() > 1 {
+        residual_shape.push(k);
+    }
+    if m <= n || ranks.iter().any(|&rank| rank < n as i64) {
+        residuals.clear();
+        residual_shape = vec![0];
+    }
+    let mut value_shape = batch.clone();
+    value_shape.push(m.min(n));
+
+    let requires_grad = a.requires_grad || b.requires_grad;
+    let mut residuals = Tensor::from_vec(residuals, &residual_shape);
+    residuals.requires_grad = requires_grad;
+    let mut singular_values = Tensor::from_vec(values, &value_shape);
+    singular_values.requires_grad = a.requires_grad;
+    Ok(Lstsq {
+        solution: solution(a, b, &columns, solutions)?,
+        residuals,
+        rank: Tensor::from_vec(ranks, &batch),
+        singular_values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lstsq;
+    use crate::types::Tensor;
+
+    fn assert_close(actual: &Tensor<f64>, expected: &Tensor<f64>) {
+        assert_eq!(actual.shape(), expected.shape());
+        for (a, e) in actual.to_vec().iter().zip(expected.to_vec()) {
+            assert!((a - e).abs() < 1e-10, "{a} != {e}");
+        }
+    }
+
+    #[test]
+    fn lstsq_overdetermined_line_fit() {
+        // Fits `y = c0 + c1 t` through (0, 1), (1, 2), (2, 2), (3, 4).
+        let a = crate::tensor![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]];
+        let b = Tensor::from(vec![1.0, 2.0, 2.0, 4.0]);
+
+        let fit = lstsq(&a, &b).unwrap();
+
+        assert_close(&fit.solution, &Tensor::from(vec![0.9, 0.9]));
+        assert_close(&fit.residuals, &Tensor::scalar(0.7));
+        assert_eq!(fit.rank.to_vec(), vec![2]);
+        assert_eq!(fit.singular_values.shape(), &[2]);
+    }
+
+    #[test]
+    fn lstsq_overdetermined_matrix_rhs() {
+        let a = crate::tensor![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]];
+        let b = crate::tensor![[1.0, 0.0], [2.0, 1.0], [2.0, 2.0], [4.0, 3.0]];
+
+        let fit = lstsq(&a, &b).unwrap();
+
+        assert_close(&fit.solution, &crate::tensor![[0.9, 0.0], [0.9, 1.0]]);
+        assert_close(&fit.residuals, &Tensor::from(vec![0.7, 0.0]));
+
+        let batched = Tensor::stack(&[&a, &a, &a], 0).unwrap();
+        let rhs = Tensor::stack(&[&b, &b, &b], 0).unwrap();
+        assert_eq!(lstsq(&batched, &rhs).unwrap().residuals.shape(), &[3, 2]);
+    }
+
+    #[test]
+    fn lstsq_rank_deficient_and_underdetermined() {
+        let rank_one = crate::tensor![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+        let wide = crate::tensor![[1.0, 1.0, 0.0], [0.0, 1.0, 1.0]];
+
+        let deficient = lstsq(&rank_one, &crate::tensor![[5.0], [10.0], [15.0]]).unwrap();
+        let minimum_norm = lstsq(&wide, &Tensor::from(vec![2.0, 2.0])).unwrap();
+
+        assert_close(&deficient.solution, &crate::tensor![[1.0], [2.0]]);
+        assert_eq!(deficient.rank.to_vec(), vec![1]);
+        assert_eq!(deficient.residuals.shape(), &[0]);
+        assert_close(
+            &minimum_norm.solution,
+            &Tensor::from(vec![2.0, 4.0, 2.0]).div_scalar(3.0),
+        );
+        assert_eq!(minimum_norm.rank.to_vec(), vec![2]);
+    }
+}
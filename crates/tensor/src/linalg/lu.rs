@@ -0,0 +1,399 @@
+use super::{Matrix, check_square, right_hand_sides, solution, stack, unstack};
+use crate::error::{Result, TensorError};
+use crate::types::Tensor;
+use num_traits::Float;
+
+/// An LU factorization with partial pivoting, `P A = L U`.
+pub(crate) struct Lu<T> {
+    /// `L` below the diagonal (with an implicit unit diagonal) and `U` on and above it.
+    pub(crate) packed: Matrix<T>,
+    /// `pivots[i]` is the row of `A` that ended up in row `i`.
+    pub(crate) pivots: Vec<usize>,
+    /// The determinant of `P`: one for an even number of row swaps, minus one otherwise.
+    pub(crate) sign: T,
+    /// Whether some pivot is exactly zero.
+    pub(crate) singular: bool,
+}
+
+/// Factors a square matrix, picking the largest remaining entry of each column as pivot. A
+/// column without a non-zero pivot is skipped and marks the matrix singular.
+pub(crate) fn lu_factor<T: Float>(a: &Matrix<T>) -> Lu<T> {
+    let n = a.rows;
+    let mut packed = a.clone();
+    let mut pivots = (0..n).collect::<Vec<_>>();
+    let mut sign = T::one();
+    let mut singular = false;
+
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| {
+                let (x, y) = (packed[(i, col)].abs(), packed[(j, col)].abs());
+                x.partial_cmp(&y).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap();
+        if pivot != col {
+            for j in 0..n {
+                packed.data.swap(col * n + j, pivot * n + j);
+            }
+            pivots.swap(col, pivot);
+            sign = -sign;
+        }
+
+        let diagonal = packed[(col, col)];
+        if diagonal.is_zero() {
+            singular = true;
+            continue;
+        }
+        for i in col + 1..n {
+            let factor = packed[(i, col)] / diagonal;
+            packed[(i, col)] = factor;
+            for j in col + 1..n {
+                packed[(i, j)] = packed[(i, j)] - factor * packed[(col, j)];
+            }
+        }
+    }
+
+    Lu {
+        packed,
+        pivots,
+        sign,
+        singular,
+    }
+}
+
+/// Solves `T x = b` in place for the triangular part of `t` selected by `upper`. With
+/// `unit_diagonal` the diagonal of `t` is taken to be all ones and not read.
+pub(crate) fn substitute<T: Float>(
+    t: &Matrix<T>,
+    b: &mut Matrix<T>,
+    upper: bool,
+    unit_diagonal: bool,
+) {
+    let n = t.rows;
+    let order = if upper {
+        (0..n).rev().collect::<Vec<_>>()
+    } else {
+        (0..n).collect()
+    };
+    for (step, &i) in order.iter().enumerate() {
+        for col in 0..b.cols {
+            let mut value = b[(i, col)];
+            for &j in &order[..step] {
+                value = value - t[(i, j)] * b[(j, col)];
+            }
+            b[(i, col)] = if unit_diagonal {
+                value
+            } else {
+                value / t[(i, i)]
+            };
+        }
+    }
+}
+
+impl<T: Float> Lu<T> {
+    /// Sign and natural logarithm of the absolute value of `det(A)`. A singular matrix has
+    /// sign zero and log-magnitude `-inf`.
+    pub(crate) fn slogdet(&self) -> (T, T) {
+        if self.singular {
+            return (T::zero(), T::neg_infinity());
+        }
+        let n = self.packed.rows;
+        (0..n).fold((self.sign, T::zero()), |(sign, log), i| {
+            let u = self.packed[(i, i)];
+            (sign * u.signum(), log + u.abs().ln())
+        })
+    }
+
+    /// Solves `A x = b` for every column of `b`.
+    pub(crate) fn solve(&self, b: &Matrix<T>) -> Matrix<T> {
+        let mut x = Matrix::zeros(b.rows, b.cols);
+        for (i, &row) in self.pivots.iter().enumerate() {
+            for col in 0..b.cols {
+                x[(i, col)] = b[(row, col)];
+            }
+        }
+        substitute(&self.packed, &mut x, false, true);
+        substitute(&self.packed, &mut x, true, false);
+        x
+    }
+}
+
+/// Solves the square systems `a x = b` by LU decomposition with partial pivoting.
+///
+/// `a` is `[..., n, n]` and `b` is either `[..., n, k]` with the same batch dimensions or, for
+/// a single matrix, a vector `[n]`; `x` has the shape of `b`. Fails with
+/// [`TensorError::SingularMatrix`] if some matrix has a zero pivot.
+pub fn solve<T: Float>(a: &Tensor<T>, b: &Tensor<T>) -> Result<Tensor<T>> {
+    let n = check_square(a)?;
+    let columns = right_hand_sides(a, b, n)?;
+    let (_, matrices) = unstack(a);
+    let (_, rhs) = unstack(&columns);
+
+    let solutions = matrices
+        .iter()
+        .zip(&rhs)
+        .map(|(m, b)| {
+            let lu = lu_factor(m);
+            if lu.singular {
+                return Err(TensorError::SingularMatrix);
+            }
+            Ok(lu.solve(b))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    solution(a, b, &columns, solutions)
+}
+
+/// Solves `a x = b` where `a` is triangular: only its upper (or, with `upper` false, lower)
+/// triangle is read. With `unit_diagonal` the diagonal is assumed to be all ones, as in the
+/// `L` factor of an LU decomposition. Shapes follow [`solve`].
+///
+/// Fails with [`TensorError::SingularMatrix`] if a diagonal entry that is read is zero.
+pub fn triangular_solve<T: Float>(
+    a: &Tensor<T>,
+    b: &Tensor<T>,
+    upper: bool,
+    unit_diagonal: bool,
+) -> Result<Tensor<T>> {
+    let n = check_square(a)?;
+    let columns = right_hand_sides(a, b, n)?;
+    let (_, matrices) = unstack(a);
+    let (_, rhs) = unstack(&columns);
+
+    let solutions = matrices
+        .iter()
+        .zip(rhs)
+        .map(|(m, mut b)| {
+            if !unit_diagonal && (0..n).any(|i| m[(i, i)].is_zero()) {
+                return Err(TensorError::SingularMatrix);
+            }
+            substitute(m, &mut b, upper, unit_diagonal);
+            Ok(b)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    solution(a, b, &columns, solutions)
+}
+
+impl<T: Float> Tensor<T> {
+    /// Inverse of each square matrix in the last two dimensions, computed from its LU
+    /// decomposition. Fails with [`TensorError::SingularMatrix`] if some matrix has a zero
+    /// pivot.
+    pub fn inverse(&self) -> Result<Tensor<T>> {
+        let n = check_square(self)?;
+        let (batch, matrices) = unstack(self);
+        let identity = Matrix::identity(n);
+        let inverses = matrices
+            .iter()
+            .map(|m| {
+                let lu = lu_factor(m);
+                if lu.singular {
+                    return Err(TensorError::SingularMatrix);
+                }
+                Ok(lu.solve(&identity))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut result = stack(&batch, n, n, inverses);
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+
+    /// Determinant of each square matrix in the last two dimensions, as the product of the
+    /// pivots of its LU decomposition. The result has the batch shape.
+    pub fn det(&self) -> Result<Tensor<T>> {
+        check_square(self)?;
+        let (batch, matrices) = unstack(self);
+        let dets = matrices
+            .iter()
+            .map(|m| {
+                let lu = lu_factor(m);
+                if lu.singular {
+                    return T::zero();
+                }
+                (0..m.rows).fold(lu.sign, |det, i| det * lu.packed[(i, i)])
+            })
+            .collect();
+
+        let mut result = Tensor::from_vec(dets, &batch);
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+
+    /// LU decomposition with partial pivoting of each square matrix in the last two
+    /// dimensions, returned as `(P, L, U)` with `A = P L U`. `P` is a permutation matrix, `L`
+    /// is unit lower-triangular and `U` upper-triangular. Singular matrices factor too,
+    /// leaving zeros on the diagonal of `U`.
+    pub fn lu(&self) -> Result<(Tensor<T>, Tensor<T>, Tensor<T>)> {
+        let n = check_square(self)?;
+        let (batch, matrices) = unstack(self);
+        let mut factors = (Vec::new(), Vec::new(), Vec::new());
+        for m in &matrices {
+            let lu = lu_factor(m);
+            let (mut p, mut l, mut u) = (
+                Matrix::zeros(n, n),
+                Matrix::zeros(n, n),
+                Matrix::zeros(n, n),
+            );
+            for i in 0..n {
+                p[(lu.pivots[i], i)] = T::one();
+                l[(i, i)] = T::one();
+                for j in 0..n {
+                    if j < i {
+                        l[(i, j)] = lu.packed[(i, j)];
+                    } else {
+                        u[(i, j)] = lu.packed[(i, j)];
+                    }
+                }
+            }
+            factors.0.push(p);
+            factors.1.push(l);
+            factors.2.push(u);
+        }
+
+        let (p, mut l, mut u) = (
+            stack(&batch, n, n, factors.0),
+            stack(&batch, n, n, factors.1),
+            stack(&batch, n, n, factors.2),
+        );
+        l.requires_grad = self.requires_grad;
+        u.requires_grad = self.requires_grad;
+        Ok((p, l, u))
+    }
+
+    /// Sign and log-magnitude of each determinant, so that `det = sign * exp(logabsdet)`.
+    /// Summing logarithms of the pivots cannot overflow or underflow the way their product
+    /// can. A singular matrix gives sign zero and `-inf`.
+    pub fn slogdet(&self) -> Result<(Tensor<T>, Tensor<T>)> {
+        check_square(self)?;
+        let (batch, matrices) = unstack(self);
+        let (signs, logs): (Vec<_>, Vec<_>) =
+            matrices.iter().map(|m| lu_factor(m).slogdet()).unzip();
+
+        let mut logabsdet = Tensor::from_vec(logs, &batch);
+        logabsdet.requires_grad = self.requires_grad;
+        Ok((Tensor::from_vec(signs, &batch), logabsdet))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve, triangular_solve};
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    fn assert_close(actual: &Tensor<f64>, expected: &[f64]) {
+        let actual = actual.to_vec();
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-10, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn solve_with_pivoting() {
+        // The zero in the corner forces a row swap.
+        let a = crate::tensor![[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 3.0]];
+        let x = crate::tensor![[1.0, -1.0], [2.0, 0.5], [3.0, 2.0]];
+        let b = a.matmul(&x);
+
+        assert_close(&solve(&a, &b).unwrap(), &x.to_vec());
+        assert_close(
+            &solve(&a, &Tensor::from(vec![7.0, 3.0, 11.0])).unwrap(),
+            &[1.0, 2.0, 3.0],
+        );
+
+        let batched = Tensor::stack(&[&a, &Tensor::eye(3)], 0).unwrap();
+        let rhs = Tensor::stack(&[&b, &x], 0).unwrap();
+        let solved = solve(&batched, &rhs).unwrap();
+        assert_eq!(solved.shape(), &[2, 3, 2]);
+        assert_close(&solved, &[x.to_vec(), x.to_vec()].concat());
+    }
+
+    #[test]
+    fn inverse() {
+        let a = crate::tensor![[4.0, 7.0], [2.0, 6.0]];
+        let batched = Tensor::stack(&[&a, &Tensor::eye(2)], 0).unwrap();
+
+        assert_close(&a.inverse().unwrap(), &[0.6, -0.7, -0.2, 0.4]);
+        assert_close(
+            &batched.inverse().unwrap(),
+            &[0.6, -0.7, -0.2, 0.4, 1.0, 0.0, 0.0, 1.0],
+        );
+        assert_eq!(
+            crate::tensor![[1.0, 2.0], [2.0, 4.0]].inverse().err(),
+            Some(TensorError::SingularMatrix)
+        );
+    }
+
+    #[test]
+    fn det_and_slogdet() {
+        let a = crate::tensor![[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 3.0]];
+        let large = Tensor::<f64>::eye(110).mul_scalar(1000.0);
+        let batched = Tensor::stack(&[&a, &Tensor::zeros(&[3, 3])], 0).unwrap();
+
+        let (sign, logabsdet) = large.slogdet().unwrap();
+
+        assert_close(&a.det().unwrap(), &[-8.0]);
+        assert_eq!(a.det().unwrap().ndim(), 0);
+        assert_eq!(batched.det().unwrap().to_vec(), vec![-8.0, 0.0]);
+        assert_eq!(large.det().unwrap().to_vec(), vec![f64::INFINITY]);
+        assert_eq!(sign.to_vec(), vec![1.0]);
+        assert_close(&logabsdet, &[330.0 * 10f64.ln()]);
+        let (signs, logs) = batched.slogdet().unwrap();
+        assert_eq!(signs.to_vec(), vec![-1.0, 0.0]);
+        assert_close(&logs.narrow(0, 0, 1).unwrap(), &[8f64.ln()]);
+        assert_eq!(logs.to_vec()[1], f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn lu_factors() {
+        let a = crate::tensor![[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [2.0, 0.0, 3.0]];
+
+        let (p, l, u) = a.lu().unwrap();
+
+        assert_close(&p.matmul(&l).matmul(&u), &a.to_vec());
+        assert_eq!(
+            p.to_vec(),
+            vec![0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0]
+        );
+        assert_eq!(l.triu(1).unwrap().to_vec(), vec![0.0; 9]);
+        assert_eq!(l.diagonal(0, 0, 1).unwrap().to_vec(), vec![1.0; 3]);
+        assert_eq!(u.tril(-1).unwrap().to_vec(), vec![0.0; 9]);
+    }
+
+    #[test]
+    fn solve_errors() {
+        let singular = crate::tensor![[1.0, 2.0], [2.0, 4.0]];
+        let b = Tensor::from(vec![1.0, 2.0]);
+
+        assert_eq!(
+            solve(&singular, &b).err(),
+            Some(TensorError::SingularMatrix)
+        );
+        assert!(matches!(
+            solve(&crate::tensor![[1.0, 2.0]], &b).err(),
+            Some(TensorError::NotSquare { .. })
+        ));
+        assert!(solve(&Tensor::<f64>::eye(3), &b).is_err());
+    }
+
+    #[test]
+    fn triangular_solve_reads_one_triangle() {
+        // The entries outside the selected triangle are garbage and must be ignored.
+        let a = crate::tensor![[2.0, 1.0], [99.0, 4.0]];
+        let b = Tensor::from(vec![4.0, 8.0]);
+
+        assert_close(&triangular_solve(&a, &b, true, false).unwrap(), &[1.0, 2.0]);
+        assert_close(
+            &triangular_solve(&a.transpose(0, 1).unwrap(), &b, false, false).unwrap(),
+            &[2.0, 1.5],
+        );
+        assert_close(
+            &triangular_solve(&a, &b, false, true).unwrap(),
+            &[4.0, -388.0],
+        );
+        assert_eq!(
+            triangular_solve(&crate::tensor![[0.0, 1.0], [0.0, 1.0]], &b, true, false).err(),
+            Some(TensorError::SingularMatrix)
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Dense linear algebra on float tensors. Matrices live in the last two dimensions, and any
+//! leading dimensions are a batch that is processed matrix by matrix.
+
+mod cholesky;
+mod eigh;
+mod functions;
+mod lstsq;
+mod lu;
+mod qr;
+mod svd;
+
+pub use cholesky::cholesky_solve;
+pub use lstsq::{Lstsq, lstsq};
+pub use lu::{solve, triangular_solve};
+pub use qr::QrMode;
+
+use crate::error::{Result, TensorError};
+use crate::types::Tensor;
+use num_traits::Float;
+use std::ops::{Index, IndexMut};
+
+/// A dense row-major matrix, the working representation of the factorizations.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Matrix<T> {
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    pub(crate) data: Vec<T>,
+}
+
+impl<T: Float> Matrix<T> {
+    pub(crate) fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix {
+            rows,
+            cols,
+            data: vec![T::zero(); rows * cols],
+        }
+    }
+
+    pub(crate) fn identity(n: usize) -> Self {
+        let mut m = Matrix::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = T::one();
+        }
+        m
+    }
+
+    pub(crate) fn transpose(&self) -> Self {
+        let mut t = Matrix::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t[(j, i)] = self[(i, j)];
+            }
+        }
+        t
+    }
+
+    pub(crate) fn matmul(&self, other: &Matrix<T>) -> Self {
+        let mut product = Matrix::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let x = self[(i, k)];
+                for j in 0..other.cols {
+                    product[(i, j)] = product[(i, j)] + x * other[(k, j)];
+                }
+            }
+        }
+        product
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+/// Fails unless `t` has at least two dimensions.
+pub(crate) fn check_matrix<T>(t: &Tensor<T>) -> Result<()> {
+    if t.ndim() < 2 {
+        return Err(TensorError::RankMismatch {
+            expected: 2,
+            actual: t.ndim(),
+        });
+    }
+    Ok(())
+}
+
+/// Fails unless `t` is a (batch of) square matrices, returning their order.
+pub(crate) fn check_square<T>(t: &Tensor<T>) -> Result<usize> {
+    check_matrix(t)?;
+    let (rows, cols) = (t.shape()[t.ndim() - 2], t.shape()[t.ndim() - 1]);
+    if rows != cols {
+        return Err(TensorError::NotSquare {
+            shape: t.shape().to_vec(),
+        });
+    }
+    Ok(rows)
+}
+
+/// Splits a tensor of at least two dimensions into its batch shape and matrices.
+pub(crate) fn unstack<T: Float>(t: &Tensor<T>) -> (Vec<usize>, Vec<Matrix<T>>) {
+    let ndim = t.ndim();
+    let (rows, cols) = (t.shape()[ndim - 2], t.shape()[ndim - 1]);
+    let data = t.to_vec();
+    let matrices = match rows * cols {
+        0 => vec![Matrix::zeros(rows, cols); t.shape()[..ndim - 2].iter().product()],
+        size => data
+            .chunks(size)
+            .map(|chunk| Matrix {
+                rows,
+                cols,
+                data: chunk.to_vec(),
+            })
+            .collect(),
+    };
+    (t.shape()[..ndim - 2].to_vec(), matrices)
+}
+
+/// Reassembles `rows x cols` matrices into a tensor of shape `batch + [rows, cols]`.
+pub(crate) fn stack<T>(
+    batch: &[usize],
+    rows: usize,
+    cols: usize,
+    matrices: Vec<Matrix<T>>,
+) -> Tensor<T> {
+    let mut shape = batch.to_vec();
+    shape.extend([rows, cols]);
+    let data = matrices.into_iter().flat_map(|m| m.data).collect();
+    Tensor::from_vec(data, &shape)
+}
+
+/// Checks that `b` holds right-hand sides for the square matrices of `a`: either a 1-D
+/// vector when `a` is a single matrix, or matrices with the same batch and row count.
+/// Returns `b` as matrices, with a vector viewed as one column.
+pub(crate) fn right_hand_sides<T: Float>(
+    a: &Tensor<T>,
+    b: &Tensor<T>,
+    n: usize,
+) -> Result<Tensor<T>> {
+    let mismatch = || TensorError::ShapeMismatch {
+        left: a.shape().to_vec(),
+        right: b.shape().to_vec(),
+    };
+    if b.ndim() == 1 {
+        if a.ndim() != 2 || b.shape()[0] != n {
+            return Err(mismatch());
+        }
+        return b.reshape(&[n as isize, 1]);
+    }
+    check_matrix(b)?;
+    if b.ndim() != a.ndim() || b.shape()[..b.ndim() - 1] != a.shape()[..a.ndim() - 1] {
+        return Err(mismatch());
+    }
+    Ok(b.share())
+}
+
+/// Restores the shape of a solution whose right-hand side `b` may have been a vector.
+pub(crate) fn solution<T: Float>(
+    a: &Tensor<T>,
+    b: &Tensor<T>,
+    columns: &Tensor<T>,
+    solutions: Vec<Matrix<T>>,
+) -> Result<Tensor<T>> {
+    let (batch, n) = (&a.shape()[..a.ndim() - 2], a.shape()[a.ndim() - 1]);
+    let cols = columns.shape()[columns.ndim() - 1];
+    let mut x = stack(batch, n, cols, solutions);
+    if b.ndim() == 1 {
+        x = x.reshape(&[n as isize])?;
+    }
+    x.requires_grad = a.requires_grad || b.requires_grad;
+    Ok(x)
+}
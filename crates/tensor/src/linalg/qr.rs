@@ -0,0 +1,138 @@
+use super::{Matrix, check_matrix, stack, unstack};
+use crate::error::Result;
+use crate::types::Tensor;
+use num_traits::Float;
+
+/// Which factors [`Tensor::qr`] returns for an `m x n` matrix with `k = min(m, n)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QrMode {
+    /// `Q` is `m x k` with orthonormal columns and `R` is `k x n`.
+    Reduced,
+    /// `Q` is an `m x m` orthogonal matrix and `R` is `m x n`.
+    Complete,
+}
+
+/// Householder QR of an `m x n` matrix, returning the complete `m x m` `Q` and `m x n` `R`.
+pub(crate) fn householder_qr<T: Float>(a: &Matrix<T>) -> (Matrix<T>, Matrix<T>) {
+    let (m, n) = (a.rows, a.cols);
+    let mut q = Matrix::identity(m);
+    let mut r = a.clone();
+
+    for j in 0..m.min(n) {
+        // Reflect `r[j.., j]` onto a multiple of the first basis vector, choosing the sign
+        // that avoids cancellation.
+        let norm = (j..m)
+            .fold(T::zero(), |acc, i| acc + r[(i, j)] * r[(i, j)])
+            .sqrt();
+        if norm.is_zero() {
+            continue;
+        }
+        let alpha = if r[(j, j)] > T::zero() { -norm } else { norm };
+        let mut v = (j..m).map(|i| r[(i, j)]).collect::<Vec<_>>();
+        v[0] = v[0] - alpha;
+        let v_norm2 = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+        let scale = (T::one() + T::one()) / v_norm2;
+
+        for col in 0..n {
+            let dot = (j..m).fold(T::zero(), |acc, i| acc + v[i - j] * r[(i, col)]);
+            for i in j..m {
+                r[(i, col)] = r[(i, col)] - scale * dot * v[i - j];
+            }
+        }
+        for row in 0..m {
+            let dot = (j..m).fold(T::zero(), |acc, i| acc + q[(row, i)] * v[i - j]);
+            for i in j..m {
+                q[(row, i)] = q[(row, i)] - scale * dot * v[i - j];
+            }
+        }
+        for i in j + 1..m {
+            r[(i, j)] = T::zero();
+        }
+    }
+    (q, r)
+}
+
+impl<T: Float> Tensor<T> {
+    /// QR decomposition `A = Q R` of each matrix in the last two dimensions by Householder
+    /// reflections, where `Q` has orthonormal columns and `R` is upper-triangular. `mode`
+    /// selects the reduced or complete factors.
+    pub fn qr(&self, mode: QrMode) -> Result<(Tensor<T>, Tensor<T>)> {
+        check_matrix(self)?;
+        let (m, n) = (self.shape()[self.ndim() - 2], self.shape()[self.ndim() - 1]);
+        let k = match mode {
+            QrMode::Reduced => m.min(n),
+            QrMode::Complete => m,
+        };
+        let (batch, matrices) = unstack(self);
+
+        let (qs, rs): (Vec<_>, Vec<_>) = matrices
+            .iter()
+            .map(|a| {
+                let (q, r) = householder_qr(a);
+                let mut q_k = Matrix::zeros(m, k);
+                let mut r_k = Matrix::zeros(k, n);
+                for i in 0..m {
+                    for j in 0..k {
+                        q_k[(i, j)] = q[(i, j)];
+                    }
+                }
+                for i in 0..k {
+                    for j in 0..n {
+                        r_k[(i, j)] = r[(i, j)];
+                    }
+                }
+                (q_k, r_k)
+            })
+            .unzip();
+
+        let mut q = stack(&batch, m, k, qs);
+        let mut r = stack(&batch, k, n, rs);
+        q.requires_grad = self.requires_grad;
+        r.requires_grad = self.requires_grad;
+        Ok((q, r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QrMode;
+    use crate::types::Tensor;
+
+    fn assert_close(actual: &Tensor<f64>, expected: &Tensor<f64>) {
+        assert_eq!(actual.shape(), expected.shape());
+        for (a, e) in actual.to_vec().iter().zip(expected.to_vec()) {
+            assert!((a - e).abs() < 1e-10, "{a} != {e}");
+        }
+    }
+
+    #[test]
+    fn qr_modes() {
+        let a = crate::tensor![[12.0, -51.0], [6.0, 167.0], [-4.0, 24.0]];
+
+        let (q, r) = a.qr(QrMode::Reduced).unwrap();
+        let (q_full, r_full) = a.qr(QrMode::Complete).unwrap();
+
+        assert_eq!((q.shape(), r.shape()), (&[3, 2][..], &[2, 2][..]));
+        assert_eq!((q_full.shape(), r_full.shape()), (&[3, 3][..], &[3, 2][..]));
+        assert_close(&q.matmul(&r), &a);
+        assert_close(&q_full.matmul(&r_full), &a);
+        assert_close(&q.transpose(0, 1).unwrap().matmul(&q), &Tensor::eye(2));
+        assert_close(
+            &q_full.matmul(&q_full.transpose(0, 1).unwrap()),
+            &Tensor::eye(3),
+        );
+        assert_eq!(r.to_vec()[2], 0.0);
+        assert!((r.to_vec()[0].abs() - 14.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn qr_wide_and_batched() {
+        let wide = crate::tensor![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        let batched = Tensor::stack(&[&wide, &-wide.clone()], 0).unwrap();
+
+        let (q, r) = batched.qr(QrMode::Reduced).unwrap();
+
+        assert_eq!((q.shape(), r.shape()), (&[2, 2, 2][..], &[2, 2, 3][..]));
+        assert_close(&q.matmul(&r), &batched);
+    }
+}
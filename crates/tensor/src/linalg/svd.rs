@@ -0,0 +1,306 @@
+use super::{Matrix, check_matrix, stack, unstack};
+use crate::error::Result;
+use crate::types::Tensor;
+use num_traits::Float;
+
+/// Sweeps after which the Jacobi iteration gives up converging further. Each sweep roughly
+/// squares the off-diagonal mass, so real inputs settle in well under a dozen.
+pub(crate) const MAX_SWEEPS: usize = 64;
+
+/// Reduced singular value decomposition `A = U diag(s) V^T` of an `m x n` matrix by one-sided
+/// Jacobi rotations.
+///
+/// With `r = min(m, n)`, `U` is `m x r`, `s` holds the `r` singular values in descending
+/// order and `V` is `n x r`. Columns of `U` for zero singular values are left zero.
+pub(crate) fn jacobi_svd<T: Float>(a: &Matrix<T>) -> (Matrix<T>, Vec<T>, Matrix<T>) {
+    if a.rows < a.cols {
+        let (v, s, u) = jacobi_svd(&a.transpose());
+        return (u, s, v);
+    }
+    let (m, n) = (a.rows, a.cols);
+    let mut u = a.clone();
+    let mut v = Matrix::identity(n);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                for k in 0..m {
+                    alpha = alpha + u[(k, p)] * u[(k, p)];
+                    beta = beta + u[(k, q)] * u[(k, q)];
+                    gamma = gamma + u[(k, p)] * u[(k, q)];
+                }
+                if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                // The rotation that makes columns `p` and `q` orthogonal.
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = (T::one() + t * t).sqrt().recip();
+                let s = c * t;
+                for w in [&mut u, &mut v] {
+                    for k in 0..w.rows {
+                        let (x, y) = (w[(k, p)], w[(k, q)]);
+                        w[(k, p)] = c * x - s * y;
+                        w[(k, q)] = s * x + c * y;
+                    }
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms = (0..n)
+        .map(|j| {
+            (0..m)
+                .fold(T::zero(), |acc, k| acc + u[(k, j)] * u[(k, j)])
+                .sqrt()
+        })
+        .collect::<Vec<_>>();
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| {
+        norms[j]
+            .partial_cmp(&norms[i])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut left = Matrix::zeros(m, n);
+    let mut right = Matrix::zeros(n, n);
+    for (to, &from) in order.iter().enumerate() {
+        for k in 0..m {
+            if !norms[from].is_zero() {
+                left[(k, to)] = u[(k, from)] / norms[from];
+            }
+        }
+        for k in 0..n {
+            right[(k, to)] = v[(k, from)];
+        }
+    }
+    let singular_values = order.iter().map(|&j| norms[j]).collect();
+    (left, singular_values, right)
+}
+
+/// Extends the orthonormal columns of `basis` to `cols` orthonormal columns, filling its
+/// zero columns and any new ones by Gram-Schmidt on the standard basis vectors.
+fn complete_basis<T: Float>(basis: &Matrix<T>, cols: usize) -> Matrix<T> {
+    let rows = basis.rows;
+    let mut result = Matrix::zeros(rows, cols);
+    let mut filled = vec![false; cols];
+    for j in 0..basis.cols {
+        filled[j] = (0..rows).any(|i| !basis[(i, j)].is_zero());
+        for i in 0..rows {
+            result[(i, j)] = basis[(i, j)];
+        }
+    }
+
+    for j in 0..cols {
+        if filled[j] {
+            continue;
+        }
+        // Of all standard basis vectors, the one with the largest component outside the span
+        // so far gives the best-conditioned direction; its residual norm is always at least
+        // `1 / sqrt(rows)`.
+        let mut best: Option<(T, Vec<T>)> = None;
+        for e in 0..rows {
+            let mut v = (0..rows)
+                .map(|i| if i == e { T::one() } else { T::zero() })
+                .collect::<Vec<_>>();
+            // Projecting twice keeps the result orthogonal to working precision.
+            for _ in 0..2 {
+                for k in (0..cols).filter(|&k| filled[k]) {
+                    let dot = (0..rows).fold(T::zero(), |acc, i| acc + v[i] * result[(i, k)]);
+                    for (i, x) in v.iter_mut().enumerate() {
+                        *x = *x - dot * result[(i, k)];
+                    }
+                }
+            }
+            let norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+            if best.as_ref().is_none_or(|(largest, _)| norm > *largest) {
+                best = Some((norm, v));
+            }
+        }
+        if let Some((norm, v)) = best {
+            for (i, x) in v.iter().enumerate() {
+                result[(i, j)] = *x / norm;
+            }
+            filled[j] = true;
+        }
+    }
+    result
+}
+
+impl<T: Float> Tensor<T> {
+    /// Singular value decomposition `A = U diag(S) V^T` of each matrix in the last two
+    /// dimensions, returned as `(U, S, Vh)` with `Vh = V^T` and `S` in descending order.
+    ///
+    /// For an `m x n` matrix with `k = min(m, n)`, `S` has `k` values. With `full_matrices`
+    /// `U` is `m x m` and `Vh` is `n x n`; otherwise they are `m x k` and `k x n`. Singular
+    /// vectors of zero singular values are completed to orthonormal bases either way.
+    pub fn svd(&self, full_matrices: bool) -> Result<(Tensor<T>, Tensor<T>, Tensor<T>)> {
+        check_matrix(self)?;
+        let (m, n) = (self.shape()[self.ndim() - 2], self.shape()[self.ndim() - 1]);
+        let k = m.min(n);
+        let (u_cols, v_cols) = if full_matrices { (m, n) } else { (k, k) };
+        let (batch, matrices) = unstack(self);
+
+        let (mut us, mut vhs, mut values) = (Vec::new(), Vec::new(), Vec::new());
+        for a in &matrices {
+            let (u, s, v) = jacobi_svd(a);
+            us.push(complete_basis(&u, u_cols));
+            vhs.push(complete_basis(&v, v_cols).transpose());
+            values.extend(s);
+        }
+
+        let mut shape = batch.clone();
+        shape.push(k);
+        let mut u = stack(&batch, m, u_cols, us);
+        let mut s = Tensor::from_vec(values, &shape);
+        let mut vh = stack(&batch, v_cols, n, vhs);
+        for t in [&mut u, &mut s, &mut vh] {
+            t.requires_grad = self.requires_grad;
+        }
+        Ok((u, s, vh))
+    }
+
+    /// Moore-Penrose pseudo-inverse of each matrix in the last two dimensions, from its
+    /// singular value decomposition. Singular values at most `rcond` times the largest one
+    /// are treated as zero, so rank-deficient and non-square matrices are handled.
+    pub fn pinverse(&self, rcond: T) -> Result<Tensor<T>> {
+        check_matrix(self)?;
+        let (rows, cols) = (self.shape()[self.ndim() - 2], self.shape()[self.ndim() - 1]);
+        let (batch, matrices) = unstack(self);
+
+        let inverses = matrices
+            .iter()
+            .map(|m| {
+                let (u, s, v) = jacobi_svd(m);
+                let cutoff = rcond * s.first().copied().unwrap_or(T::zero());
+                let mut inverse = Matrix::zeros(cols, rows);
+                for (r, &value) in s.iter().enumerate() {
+                    if value <= cutoff || value.is_zero() {
+                        continue;
+                    }
+                    for i in 0..cols {
+                        let scaled = v[(i, r)] / value;
+                        for j in 0..rows {
+                            inverse[(i, j)] = inverse[(i, j)] + scaled * u[(j, r)];
+                        }
+                    }
+                }
+                inverse
+            })
+            .collect();
+
+        let mut result = stack(&batch, cols, rows, inverses);
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::Tensor;
+
+    fn assert_close(actual: &Tensor<f64>, expected: &Tensor<f64>) {
+        assert_eq!(actual.shape(), expected.shape());
+        for (a, e) in actual.to_vec().iter().zip(expected.to_vec()) {
+            assert!((a - e).abs() < 1e-10, "{a} != {e}");
+        }
+    }
+
+    fn assert_orthonormal_columns(q: &Tensor<f64>) {
+        let n = q.shape()[1];
+        assert_close(&q.transpose(0, 1).unwrap().matmul(q), &Tensor::eye(n));
+    }
+
+    #[test]
+    fn svd_reduced_and_full() {
+        let a = crate::tensor![[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]];
+
+        let (u, s, vh) = a.svd(false).unwrap();
+        let (u_full, s_full, vh_full) = a.svd(true).unwrap();
+
+        assert_eq!(
+            (u.shape(), s.shape(), vh.shape()),
+            (&[2, 2][..], &[2][..], &[2, 3][..])
+        );
+        assert_eq!(vh_full.shape(), &[3, 3]);
+        assert_close(&s, &Tensor::from(vec![5.0, 3.0]));
+        assert_close(&s_full, &s);
+        assert_close(&u.matmul(&Tensor::diag(&s.to_vec())).matmul(&vh), &a);
+        assert_close(&u_full, &u);
+        assert_orthonormal_columns(&u);
+        assert_orthonormal_columns(&vh_full.transpose(0, 1).unwrap());
+    }
+
+    #[test]
+    fn svd_rank_deficient_and_batched() {
+        let rank_one = crate::tensor![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+        let batched = Tensor::stack(&[&rank_one, &Tensor::zeros(&[3, 2])], 0).unwrap();
+
+        let (u, s, vh) = batched.svd(true).unwrap();
+
+        assert_eq!(
+            (u.shape(), s.shape(), vh.shape()),
+            (&[2, 3, 3][..], &[2, 2][..], &[2, 2, 2][..])
+        );
+        assert!((s.to_vec()[0] - 70.0f64.sqrt()).abs() < 1e-10);
+        assert_eq!(s.to_vec()[1..], [0.0, 0.0, 0.0]);
+        for i in 0..2 {
+            let matrix = |t: &Tensor<f64>| t.narrow(0, i, 1).unwrap().squeeze(0).unwrap();
+            assert_orthonormal_columns(&matrix(&u));
+            assert_orthonormal_columns(&matrix(&vh));
+        }
+    }
+
+    #[test]
+    fn svd_full_completes_spread_out_directions() {
+        // Differences of consecutive coordinates: the columns span everything orthogonal to
+        // the all-ones vector, so the missing left singular vector has no dominant coordinate.
+        let rows = 16;
+        let mut data = vec![0.0; rows * (rows - 1)];
+        for j in 0..rows - 1 {
+            data[j * (rows - 1) + j] = 1.0;
+            data[(j + 1) * (rows - 1) + j] = -1.0;
+        }
+        let a = Tensor::from_vec(data, &[rows, rows - 1]);
+
+        let (u, s, vh) = a.svd(true).unwrap();
+
+        assert_eq!(u.shape(), &[rows, rows]);
+        assert_orthonormal_columns(&u);
+        assert_orthonormal_columns(&vh.transpose(0, 1).unwrap());
+        let sigma = Tensor::diag(&s.to_vec());
+        let reduced = u.narrow(1, 0, rows - 1).unwrap();
+        assert_close(&reduced.matmul(&sigma).matmul(&vh), &a);
+    }
+
+    #[test]
+    fn pinverse() {
+        let square = crate::tensor![[4.0, 7.0], [2.0, 6.0]];
+        let tall = crate::tensor![[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
+        let rank_one = crate::tensor![[1.0, 2.0], [2.0, 4.0]];
+
+        assert_close(&square.pinverse(1e-12).unwrap(), &square.inverse().unwrap());
+        assert_close(
+            &tall.pinverse(1e-12).unwrap().matmul(&tall),
+            &Tensor::eye(2),
+        );
+        assert_close(
+            &rank_one.pinverse(1e-12).unwrap(),
+            &rank_one.div_scalar(25.0),
+        );
+        assert_eq!(
+            Tensor::<f64>::zeros(&[2, 3, 2])
+                .pinverse(1e-12)
+                .unwrap()
+                .shape(),
+            &[2, 2, 3]
+        );
+    }
+}
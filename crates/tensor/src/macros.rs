@@ -0,0 +1,58 @@
+use crate::types::Tensor;
+
+/// Builds a [`Tensor`](crate::types::Tensor) from nested array syntax.
+///
+/// The shape is inferred from the nesting and the strides are computed automatically.
+/// Ragged rows are rejected with a panic.
+///
+/// ```
+/// use tensor::tensor;
+///
+/// let t = tensor![[1., 2.], [3., 4.]];
+/// assert_eq!(t.shape(), &[2, 2]);
+/// assert_eq!(t.strides(), &[2, 1]);
+/// ```
+#[macro_export]
+macro_rules! tensor {
+    ($([$($inner:tt)*]),+ $(,)?) => {
+        $crate::macros::stack_rows(vec![$($crate::tensor![$($inner)*]),+])
+    };
+    ($($x:expr),* $(,)?) => {{
+        let data = vec![$($x),*];
+        let len = data.len();
+        $crate::types::Tensor::from_vec(data, &[len])
+    }};
+}
+
+/// Joins equally shaped `rows` along a new leading dimension. Used by [`tensor!`].
+#[doc(hidden)]
+pub fn stack_rows<T: Clone>(rows: Vec<Tensor<T>>) -> Tensor<T> {
+    Tensor::stack(&rows.iter().collect::<Vec<_>>(), 0)
+        .unwrap_or_else(|e| panic!("tensor! rows must have matching shapes: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn nested_literal() {
+        let t = crate::tensor![[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]];
+
+        assert_eq!(t.shape(), &[2, 2, 3]);
+        assert_eq!(t.strides(), &[6, 3, 1]);
+        assert_eq!(*t.base.data.borrow(), (1..=12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn flat_literal() {
+        let t = crate::tensor![1.0, 2.0, 3.0];
+
+        assert_eq!(t.shape(), &[3]);
+        assert_eq!(*t.base.data.borrow(), vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "matching shapes")]
+    fn ragged_literal_panics() {
+        let _ = crate::tensor![[1, 2], [3]];
+    }
+}
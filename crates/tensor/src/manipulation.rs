@@ -0,0 +1,454 @@
+use crate::error::{Result, TensorError};
+use crate::types::{BaseTensor, Tensor, for_each_index};
+use crate::view::check_dim;
+use num_traits::Zero;
+
+impl<T: Clone> Tensor<T> {
+    /// Circularly shifts elements by `shifts[i]` positions along `dims[i]`. Elements pushed
+    /// past the end wrap around to the start; negative shifts move towards the start.
+    ///
+    /// With empty `dims`, the tensor is rolled as if it were flattened and then restored to
+    /// its original shape, in which case `shifts` must have exactly one entry.
+    pub fn roll(&self, shifts: &[isize], dims: &[usize]) -> Result<Tensor<T>> {
+        if dims.is_empty() {
+            if shifts.len() != 1 {
+                return Err(TensorError::LengthMismatch {
+                    expected: 1,
+                    actual: shifts.len(),
+                });
+            }
+            let flat = self.ravel().roll(shifts, &[0])?;
+            return Ok(Tensor::from_vec(flat.to_vec(), self.shape()));
+        }
+        if shifts.len() != dims.len() {
+            return Err(TensorError::LengthMismatch {
+                expected: dims.len(),
+                actual: shifts.len(),
+            });
+        }
+
+        // Net shift per dimension, normalized into `0..size`.
+        let mut net = vec![0isize; self.ndim()];
+        for (&shift, &dim) in shifts.iter().zip(dims) {
+            check_dim(dim, self.ndim())?;
+            net[dim] += shift;
+        }
+        let net = net
+            .iter()
+            .zip(self.shape())
+            .map(|(&shift, &size)| match size {
+                0 => 0,
+                _ => shift.rem_euclid(size as isize) as usize,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(gather_from(&self.base, self.shape(), |index, src| {
+            for (d, &i) in index.iter().enumerate() {
+                src[d] = (i + self.shape()[d] - net[d]) % self.shape()[d];
+            }
+        }))
+    }
+
+    /// Tiles the tensor `repeats[i]` times along each dimension, copying the data
+    /// (PyTorch `repeat` semantics). `repeats` may have more entries than the tensor has
+    /// dimensions, in which case new leading dimensions are added.
+    pub fn repeat(&self, repeats: &[usize]) -> Result<Tensor<T>> {
+        if repeats.len() < self.ndim() {
+            return Err(TensorError::LengthMismatch {
+                expected: self.ndim(),
+                actual: repeats.len(),
+            });
+        }
+
+        // View the source with size-1 leading dimensions so both shapes have the same rank.
+        let leading = repeats.len() - self.ndim();
+        let mut source = self.base.share();
+        source.shape = std::iter::repeat_n(1, leading)
+            .chain(self.shape().iter().copied())
+            .collect();
+        source.strides.splice(0..0, std::iter::repeat_n(0, leading));
+
+        let shape = source
+            .shape
+            .iter()
+            .zip(repeats)
+            .map(|(&size, &times)| size * times)
+            .collect::<Vec<_>>();
+        Ok(gather_from(&source, &shape, |index, src| {
+            for (d, &i) in index.iter().enumerate() {
+                src[d] = i % source.shape[d];
+            }
+        }))
+    }
+
+    /// Like [`Tensor::repeat`], but with NumPy `tile` semantics: if `reps` has fewer entries
+    /// than the tensor has dimensions, it is padded with leading ones.
+    pub fn tile(&self, reps: &[usize]) -> Tensor<T> {
+        let mut padded = vec![1; self.ndim().saturating_sub(reps.len())];
+        padded.extend_from_slice(reps);
+        self.repeat(&padded)
+            .expect("padded reps always cover every dimension")
+    }
+
+    /// Repeats each element `repeats` times along `dim`, so `[a, b]` becomes `[a, a, b, b]`.
+    pub fn repeat_interleave(&self, repeats: usize, dim: usize) -> Result<Tensor<T>> {
+        check_dim(dim, self.ndim())?;
+
+        let mut shape = self.shape().to_vec();
+        shape[dim] *= repeats;
+        Ok(gather_from(&self.base, &shape, |index, src| {
+            src.copy_from_slice(index);
+            src[dim] /= repeats;
+        }))
+    }
+
+    /// Concatenates `tensors` along the existing dimension `dim` into a new contiguous tensor.
+    /// All other dimensions must match.
+    pub fn cat(tensors: &[&Tensor<T>], dim: usize) -> Result<Tensor<T>> {
+        let first = tensors.first().ok_or(TensorError::EmptyInput)?;
+        check_dim(dim, first.ndim())?;
+        for t in &tensors[1..] {
+            if t.ndim() != first.ndim() {
+                return Err(TensorError::RankMismatch {
+                    expected: first.ndim(),
+                    actual: t.ndim(),
+                });
+            }
+            let same_except_dim =
+                (0..first.ndim()).all(|d| d == dim || t.shape()[d] == first.shape()[d]);
+            if !same_except_dim {
+                return Err(TensorError::ShapeMismatch {
+                    left: first.shape().to_vec(),
+                    right: t.shape().to_vec(),
+                });
+            }
+        }
+
+        let mut shape = first.shape().to_vec();
+        shape[dim] = tensors.iter().map(|t| t.shape()[dim]).sum();
+        let outer = shape[..dim].iter().product::<usize>();
+        let inner = shape[dim + 1..].iter().product::<usize>();
+
+        // Each input contributes one contiguous chunk of `size(dim) * inner` elements per
+        // outer index; interleave those chunks in input order.
+        let values = tensors.iter().map(|t| t.to_vec()).collect::<Vec<_>>();
+        let mut result = Vec::with_capacity(shape.iter().product());
+        for o in 0..outer {
+            for (t, values) in tensors.iter().zip(&values) {
+                let chunk = t.shape()[dim] * inner;
+                result.extend_from_slice(&values[o * chunk..(o + 1) * chunk]);
+            }
+        }
+        Ok(Tensor::from_vec(result, &shape))
+    }
+}
+
+/// How [`Tensor::pad`] fills the new border elements.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PadMode<T> {
+    /// Fill with a fixed value.
+    Constant(T),
+    /// Mirror the tensor without repeating the edge: `[1, 2, 3]` padded by 2 becomes
+    /// `[3, 2, 1, 2, 3, 2, 1]`. Each pad must be smaller than its dimension.
+    Reflect,
+    /// Repeat the edge element: `[1, 2, 3]` padded by 2 becomes `[1, 1, 1, 2, 3, 3, 3]`.
+    Replicate,
+}
+
+impl<T: Clone> Tensor<T> {
+    /// Pads the trailing dimensions of the tensor. `padding[i]` is the `(before, after)` amount
+    /// for dimension `ndim - padding.len() + i`, so the last pair pads the last dimension.
+    pub fn pad(&self, padding: &[(usize, usize)], mode: PadMode<T>) -> Result<Tensor<T>> {
+        let leading =
+            self.ndim()
+                .checked_sub(padding.len())
+                .ok_or(TensorError::LengthMismatch {
+                    expected: self.ndim(),
+                    actual: padding.len(),
+                })?;
+        let mut pads = vec![(0, 0); leading];
+        pads.extend_from_slice(padding);
+
+        for (dim, (&size, &(before, after))) in self.shape().iter().zip(&pads).enumerate() {
+            let invalid = match mode {
+                PadMode::Constant(_) => false,
+                PadMode::Reflect => before.max(after) >= size && before.max(after) > 0,
+                PadMode::Replicate => size == 0 && before.max(after) > 0,
+            };
+            if invalid {
+                return Err(TensorError::InvalidPadding {
+                    dim,
+                    pad: before.max(after),
+                    size,
+                });
+            }
+        }
+
+        let shape = self
+            .shape()
+            .iter()
+            .zip(&pads)
+            .map(|(&size, &(before, after))| before + size + after)
+            .collect::<Vec<_>>();
+        let data = self.base.data.borrow();
+        let mut result = Vec::with_capacity(shape.iter().product());
+        let mut src = vec![0; self.ndim()];
+        for_each_index(&shape, |index| {
+            let mut inside = true;
+            for (d, &i) in index.iter().enumerate() {
+                let size = self.shape()[d] as isize;
+                let i = i as isize - pads[d].0 as isize;
+                src[d] = match mode {
+                    _ if (0..size).contains(&i) => i as usize,
+                    PadMode::Constant(_) => {
+                        inside = false;
+                        break;
+                    }
+                    PadMode::Reflect if i < 0 => (-i) as usize,
+                    PadMode::Reflect => (2 * (size - 1) - i) as usize,
+                    PadMode::Replicate => i.clamp(0, size - 1) as usize,
+                };
+            }
+            match &mode {
+                PadMode::Constant(value) if !inside => result.push(value.clone()),
+                _ => result.push(data[self.base.storage_offset(&src)].clone()),
+            }
+        });
+        Ok(Tensor::from_vec(result, &shape))
+    }
+
+    /// Joins equally shaped `tensors` along a new dimension inserted at `dim` (`0..=ndim`).
+    pub fn stack(tensors: &[&Tensor<T>], dim: usize) -> Result<Tensor<T>> {
+        let first = tensors.first().ok_or(TensorError::EmptyInput)?;
+        if let Some(t) = tensors.iter().find(|t| t.shape() != first.shape()) {
+            return Err(TensorError::ShapeMismatch {
+                left: first.shape().to_vec(),
+                right: t.shape().to_vec(),
+            });
+        }
+
+        let expanded = tensors
+            .iter()
+            .map(|t| t.unsqueeze(dim))
+            .collect::<Result<Vec<_>>>()?;
+        Tensor::cat(&expanded.iter().collect::<Vec<_>>(), dim)
+    }
+}
+
+/// Builds a contiguous tensor of `shape` whose element at each multi-index is read from
+/// `source` at the index written by `map_index(output_index, source_index)`.
+pub(crate) fn gather_from<T: Clone>(
+    source: &BaseTensor<T>,
+    shape: &[usize],
+    mut map_index: impl FnMut(&[usize], &mut [usize]),
+) -> Tensor<T> {
+    let data = source.data.borrow();
+    let mut result = Vec::with_capacity(shape.iter().product());
+    let mut src = vec![0; source.ndim()];
+    for_each_index(shape, |index| {
+        map_index(index, &mut src);
+        result.push(data[source.storage_offset(&src)].clone());
+    });
+    Tensor::from_vec(result, shape)
+}
+
+impl<T: Clone + Zero> Tensor<T> {
+    /// Keeps the elements on and below diagonal `k` of the matrices in the last two
+    /// dimensions and zeroes the rest. Positive `k` moves the diagonal up, negative down.
+    pub fn tril(&self, k: isize) -> Result<Tensor<T>> {
+        self.triangle(|row, col| col - row <= k)
+    }
+
+    /// Keeps the elements on and above diagonal `k` of the matrices in the last two
+    /// dimensions and zeroes the rest; see [`Tensor::tril`].
+    pub fn triu(&self, k: isize) -> Result<Tensor<T>> {
+        self.triangle(|row, col| col - row >= k)
+    }
+
+    fn triangle(&self, keep: impl Fn(isize, isize) -> bool) -> Result<Tensor<T>> {
+        if self.ndim() < 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: self.ndim(),
+            });
+        }
+        let cols = self.shape()[self.ndim() - 1];
+        let rows = self.shape()[self.ndim() - 2];
+        let data = self
+            .to_vec()
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let (row, col) = ((i / cols % rows) as isize, (i % cols) as isize);
+                if keep(row, col) { x } else { T::zero() }
+            })
+            .collect();
+
+        let mut result = Tensor::from_vec(data, self.shape());
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PadMode;
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    #[test]
+    fn roll_along_dims() {
+        let t = Tensor::arange(0, 6, 1).reshape(&[2, 3]).unwrap();
+
+        assert_eq!(t.roll(&[1], &[1]).unwrap().to_vec(), vec![2, 0, 1, 5, 3, 4]);
+        assert_eq!(
+            t.roll(&[-1], &[1]).unwrap().to_vec(),
+            vec![1, 2, 0, 4, 5, 3]
+        );
+        assert_eq!(
+            t.roll(&[1, 1], &[0, 1]).unwrap().to_vec(),
+            vec![5, 3, 4, 2, 0, 1]
+        );
+    }
+
+    #[test]
+    fn repeat_and_tile() {
+        let t = Tensor::from(vec![1, 2]);
+        let repeated = t.repeat(&[2, 2]).unwrap();
+
+        assert_eq!(repeated.shape(), &[2, 4]);
+        assert_eq!(repeated.to_vec(), vec![1, 2, 1, 2, 1, 2, 1, 2]);
+        assert_eq!(t.tile(&[3]).to_vec(), vec![1, 2, 1, 2, 1, 2]);
+        assert!(repeated.repeat(&[2]).is_err());
+        assert_eq!(repeated.tile(&[2]).shape(), &[2, 8]);
+    }
+
+    #[test]
+    fn repeat_interleave() {
+        let t = crate::tensor![[1, 2], [3, 4]];
+
+        assert_eq!(
+            t.repeat_interleave(2, 0).unwrap().to_vec(),
+            vec![1, 2, 1, 2, 3, 4, 3, 4]
+        );
+        assert_eq!(
+            t.repeat_interleave(2, 1).unwrap().to_vec(),
+            vec![1, 1, 2, 2, 3, 3, 4, 4]
+        );
+    }
+
+    #[test]
+    fn cat() {
+        let a = crate::tensor![[1, 2], [3, 4]];
+        let b = crate::tensor![[5, 6]];
+        let c = crate::tensor![[7], [8]];
+
+        let rows = Tensor::cat(&[&a, &b], 0).unwrap();
+        let cols = Tensor::cat(&[&a, &c], 1).unwrap();
+
+        assert_eq!(rows.shape(), &[3, 2]);
+        assert_eq!(rows.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(cols.shape(), &[2, 3]);
+        assert_eq!(cols.to_vec(), vec![1, 2, 7, 3, 4, 8]);
+    }
+
+    #[test]
+    fn cat_validates_inputs() {
+        let a = crate::tensor![[1, 2], [3, 4]];
+        let b = crate::tensor![[5, 6]];
+
+        assert_eq!(
+            Tensor::cat(&[&a, &b], 1).err(),
+            Some(TensorError::ShapeMismatch {
+                left: vec![2, 2],
+                right: vec![1, 2],
+            })
+        );
+        assert_eq!(
+            Tensor::<i32>::cat(&[], 0).err(),
+            Some(TensorError::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn stack() {
+        let a = Tensor::from(vec![1, 2, 3]);
+        let b = Tensor::from(vec![4, 5, 6]);
+
+        let rows = Tensor::stack(&[&a, &b], 0).unwrap();
+        let cols = Tensor::stack(&[&a, &b], 1).unwrap();
+
+        assert_eq!(rows.shape(), &[2, 3]);
+        assert_eq!(rows.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(cols.shape(), &[3, 2]);
+        assert_eq!(cols.to_vec(), vec![1, 4, 2, 5, 3, 6]);
+        assert!(Tensor::stack(&[&a, &Tensor::from(vec![1])], 0).is_err());
+    }
+
+    #[test]
+    fn pad_modes() {
+        let t = Tensor::from(vec![1, 2, 3]);
+
+        let constant = t.pad(&[(1, 2)], PadMode::Constant(0)).unwrap();
+        let reflect = t.pad(&[(2, 2)], PadMode::Reflect).unwrap();
+        let replicate = t.pad(&[(2, 2)], PadMode::Replicate).unwrap();
+
+        assert_eq!(constant.to_vec(), vec![0, 1, 2, 3, 0, 0]);
+        assert_eq!(reflect.to_vec(), vec![3, 2, 1, 2, 3, 2, 1]);
+        assert_eq!(replicate.to_vec(), vec![1, 1, 1, 2, 3, 3, 3]);
+        assert_eq!(
+            t.pad(&[(3, 0)], PadMode::Reflect).err(),
+            Some(TensorError::InvalidPadding {
+                dim: 0,
+                pad: 3,
+                size: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn pad_trailing_dims() {
+        let t = crate::tensor![[1, 2], [3, 4]];
+        let padded = t.pad(&[(1, 0)], PadMode::Constant(9)).unwrap();
+        let both = t.pad(&[(1, 0), (0, 1)], PadMode::Replicate).unwrap();
+
+        assert_eq!(padded.shape(), &[2, 3]);
+        assert_eq!(padded.to_vec(), vec![9, 1, 2, 9, 3, 4]);
+        assert_eq!(both.shape(), &[3, 3]);
+        assert_eq!(both.to_vec(), vec![1, 2, 2, 1, 2, 2, 3, 4, 4]);
+    }
+
+    #[test]
+    fn roll_flattened() {
+        let t = Tensor::arange(0, 6, 1).reshape(&[2, 3]).unwrap();
+        let rolled = t.roll(&[2], &[]).unwrap();
+
+        assert_eq!(rolled.shape(), &[2, 3]);
+        assert_eq!(rolled.to_vec(), vec![4, 5, 0, 1, 2, 3]);
+        assert_eq!(
+            t.roll(&[1, 2], &[0]).err(),
+            Some(TensorError::LengthMismatch {
+                expected: 1,
+                actual: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn tril_and_triu() {
+        let t = crate::tensor![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        let batched = Tensor::<i32>::ones(&[2, 2, 3]);
+
+        assert_eq!(t.tril(0).unwrap().to_vec(), vec![1, 0, 0, 4, 5, 0, 7, 8, 9]);
+        assert_eq!(
+            t.tril(-1).unwrap().to_vec(),
+            vec![0, 0, 0, 4, 0, 0, 7, 8, 0]
+        );
+        assert_eq!(t.triu(1).unwrap().to_vec(), vec![0, 2, 3, 0, 0, 6, 0, 0, 0]);
+        assert_eq!(
+            batched.triu(0).unwrap().to_vec(),
+            vec![1, 1, 1, 0, 1, 1, 1, 1, 1, 0, 1, 1]
+        );
+        assert!(Tensor::from(vec![1, 2]).tril(0).is_err());
+    }
+}
@@ -0,0 +1,334 @@
+use crate::error::Result;
+use crate::ops::special::{erf, via_f64};
+use crate::ops::unary::{sigmoid, softplus, unary_backward};
+use crate::types::Tensor;
+use num_traits::Float;
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+
+/// Which formula [`Tensor::gelu`] evaluates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeluApproximation {
+    /// `x * Phi(x)` with the exact normal CDF, computed through `erf`.
+    None,
+    /// `0.5 * x * (1 + tanh(sqrt(2/pi) * (x + 0.044715 * x^3)))`, as used by GPT-2 and BERT.
+    Tanh,
+}
+
+const GELU_TANH_COEFFICIENT: f64 = 0.044715;
+
+fn constant<T: Float>(value: f64) -> T {
+    T::from(value).unwrap()
+}
+
+/// Standard normal CDF and density at `x`.
+fn normal_cdf_and_pdf<T: Float>(x: T) -> (T, T) {
+    let half = constant::<T>(0.5);
+    let cdf = half * (T::one() + via_f64(x * constant(FRAC_1_SQRT_2), erf));
+    // 1 / sqrt(2 pi) = FRAC_2_SQRT_PI * FRAC_1_SQRT_2 / 2.
+    let pdf = (-half * x * x).exp() * constant(FRAC_2_SQRT_PI * FRAC_1_SQRT_2 * 0.5);
+    (cdf, pdf)
+}
+
+/// `sqrt(2/pi) * (x + 0.044715 * x^3)`, the argument of `tanh` in the GELU approximation.
+fn gelu_tanh_inner<T: Float>(x: T) -> T {
+    constant::<T>(FRAC_2_SQRT_PI * FRAC_1_SQRT_2)
+        * (x + constant::<T>(GELU_TANH_COEFFICIENT) * x * x * x)
+}
+
+impl<T: Float> Tensor<T> {
+    /// Rectified linear unit, `max(x, 0)`.
+    pub fn relu(&self) -> Tensor<T> {
+        self.map(|x| if x > T::zero() { x } else { T::zero() })
+    }
+
+    /// Like [`Tensor::relu`], but negative inputs are scaled by `negative_slope` instead of
+    /// zeroed.
+    pub fn leaky_relu(&self, negative_slope: T) -> Tensor<T> {
+        self.map(|x| if x > T::zero() { x } else { negative_slope * x })
+    }
+
+    /// Exponential linear unit: `x` for positive inputs, `alpha * (e^x - 1)` otherwise.
+    pub fn elu(&self, alpha: T) -> Tensor<T> {
+        self.map(|x| if x > T::zero() { x } else { alpha * x.exp_m1() })
+    }
+
+    /// Gaussian error linear unit, `x * Phi(x)`, exact or with the tanh approximation.
+    pub fn gelu(&self, approximation: GeluApproximation) -> Tensor<T> {
+        let half = constant::<T>(0.5);
+        match approximation {
+            GeluApproximation::None => self.map(|x| x * normal_cdf_and_pdf(x).0),
+            GeluApproximation::Tanh => {
+                self.map(|x| half * x * (T::one() + gelu_tanh_inner(x).tanh()))
+            }
+        }
+    }
+
+    /// Sigmoid linear unit, `x * sigmoid(x)`.
+    pub fn silu(&self) -> Tensor<T> {
+        self.map(|x| x * sigmoid(x))
+    }
+
+    /// Alias of [`Tensor::silu`].
+    pub fn swish(&self) -> Tensor<T> {
+        self.silu()
+    }
+
+    /// Mish, `x * tanh(softplus(x))`.
+    pub fn mish(&self) -> Tensor<T> {
+        self.map(|x| x * softplus(x).tanh())
+    }
+
+    /// Clamps every element to `[min, max]`, passing gradients only strictly inside.
+    pub fn hardtanh(&self, min: T, max: T) -> Tensor<T> {
+        self.map(|x| x.max(min).min(max))
+    }
+}
+
+impl<T: Float> Tensor<T> {
+    /// Normalizes each lane along `dim` into a probability distribution,
+    /// `e^x_i / sum_j e^x_j`. The lane maximum is subtracted before exponentiating, so large
+    /// inputs cannot overflow.
+    pub fn softmax(&self, dim: usize) -> Result<Tensor<T>> {
+        self.map_lanes(dim, |lane| {
+            let max = lane.iter().fold(T::neg_infinity(), |acc, &x| acc.max(x));
+            let mut sum = T::zero();
+            for x in lane.iter_mut() {
+                *x = (*x - max).exp();
+                sum = sum + *x;
+            }
+            for x in lane.iter_mut() {
+                *x = *x / sum;
+            }
+        })
+    }
+
+    /// Logarithm of [`Tensor::softmax`] along `dim`, computed directly as
+    /// `x_i - max - ln(sum_j e^(x_j - max))` rather than by taking the log of small
+    /// probabilities.
+    pub fn log_softmax(&self, dim: usize) -> Result<Tensor<T>> {
+        self.map_lanes(dim, |lane| {
+            let max = lane.iter().fold(T::neg_infinity(), |acc, &x| acc.max(x));
+            let log_sum = lane
+                .iter()
+                .fold(T::zero(), |acc, &x| acc + (x - max).exp())
+                .ln();
+            for x in lane.iter_mut() {
+                *x = *x - max - log_sum;
+            }
+        })
+    }
+}
+
+/// Gradient of [`Tensor::relu`] with respect to `input`. Zero at the kink.
+pub fn relu_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    unary_backward(input, grad_output, |x| {
+        if x > T::zero() { T::one() } else { T::zero() }
+    })
+}
+
+/// Gradient of [`Tensor::leaky_relu`] with respect to `input`.
+pub fn leaky_relu_backward<T: Float>(
+    input: &Tensor<T>,
+    grad_output: &Tensor<T>,
+    negative_slope: T,
+) -> Result<Tensor<T>> {
+    unary_backward(input, grad_output, |x| {
+        if x > T::zero() {
+            T::one()
+        } else {
+            negative_slope
+        }
+    })
+}
+
+/// Gradient of [`Tensor::elu`] with respect to `input`.
+pub fn elu_backward<T: Float>(
+    input: &Tensor<T>,
+    grad_output: &Tensor<T>,
+    alpha: T,
+) -> Result<Tensor<T>> {
+    unary_backward(input, grad_output, |x| {
+        if x > T::zero() {
+            T::one()
+        } else {
+            alpha * x.exp()
+        }
+    })
+}
+
+/// Gradient of [`Tensor::gelu`] with respect to `input`, for the same `approximation`.
+pub fn gelu_backward<T: Float>(
+    input: &Tensor<T>,
+    grad_output: &Tensor<T>,
+    approximation: GeluApproximation,
+) -> Result<Tensor<T>> {
+    let half = constant::<T>(0.5);
+    match approximation {
+        GeluApproximation::None => unary_backward(input, grad_output, |x| {
+            let (cdf, pdf) = normal_cdf_and_pdf(x);
+            cdf + x * pdf
+        }),
+        GeluApproximation::Tanh => unary_backward(input, grad_output, |x| {
+            let tanh = gelu_tanh_inner(x).tanh();
+            let inner_derivative = constant::<T>(FRAC_2_SQRT_PI * FRAC_1_SQRT_2)
+                * (T::one() + constant::<T>(3.0 * GELU_TANH_COEFFICIENT) * x * x);
+            half * (T::one() + tanh) + half * x * (T::one() - tanh * tanh) * inner_derivative
+        }),
+    }
+}
+
+/// Gradient of [`Tensor::silu`] with respect to `input`.
+pub fn silu_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    unary_backward(input, grad_output, |x| {
+        let s = sigmoid(x);
+        s * (T::one() + x * (T::one() - s))
+    })
+}
+
+/// Gradient of [`Tensor::mish`] with respect to `input`.
+pub fn mish_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    unary_backward(input, grad_output, |x| {
+        let tanh = softplus(x).tanh();
+        tanh + x * (T::one() - tanh * tanh) * sigmoid(x)
+    })
+}
+
+/// Gradient of [`Tensor::hardtanh`] with respect to `input`.
+pub fn hardtanh_backward<T: Float>(
+    input: &Tensor<T>,
+    grad_output: &Tensor<T>,
+    min: T,
+    max: T,
+) -> Result<Tensor<T>> {
+    unary_backward(input, grad_output, |x| {
+        if x > min && x < max {
+            T::one()
+        } else {
+            T::zero()
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        GeluApproximation, elu_backward, gelu_backward, hardtanh_backward, leaky_relu_backward,
+        mish_backward, relu_backward, silu_backward,
+    };
+    use crate::types::Tensor;
+
+    fn assert_close(actual: Vec<f64>, expected: &[f64]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{} != {}", a, e);
+        }
+    }
+
+    /// Central finite difference of `forward` at each element of `x`.
+    fn numeric_gradient(x: &[f64], forward: impl Fn(&Tensor<f64>) -> Tensor<f64>) -> Vec<f64> {
+        let h = 1e-6;
+        x.iter()
+            .map(|&x| {
+                let up = forward(&Tensor::from(vec![x + h])).to_vec()[0];
+                let down = forward(&Tensor::from(vec![x - h])).to_vec()[0];
+                (up - down) / (2.0 * h)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn piecewise_activations() {
+        let t = Tensor::from(vec![-2.0, 0.0, 3.0]);
+
+        assert_eq!(t.relu().to_vec(), vec![0.0, 0.0, 3.0]);
+        assert_eq!(t.leaky_relu(0.1).to_vec(), vec![-0.2, 0.0, 3.0]);
+        assert_eq!(t.hardtanh(-1.0, 1.0).to_vec(), vec![-1.0, 0.0, 1.0]);
+        assert_close(t.elu(1.0).to_vec(), &[(-2.0f64).exp_m1(), 0.0, 3.0]);
+        assert_eq!(
+            relu_backward(&t, &Tensor::from(vec![5.0; 3]))
+                .unwrap()
+                .to_vec(),
+            vec![0.0, 0.0, 5.0]
+        );
+    }
+
+    #[test]
+    fn smooth_activations() {
+        let t = Tensor::from(vec![-1.0, 0.0, 1.0]);
+
+        assert_close(
+            t.gelu(GeluApproximation::None).to_vec(),
+            &[-0.158_655_253_931_457_05, 0.0, 0.841_344_746_068_542_9],
+        );
+        assert_close(
+            t.gelu(GeluApproximation::Tanh).to_vec(),
+            &[-0.158_808_009_391_723_24, 0.0, 0.841_191_990_608_276_8],
+        );
+        assert_close(
+            t.silu().to_vec(),
+            &[-0.268_941_421_369_995_1, 0.0, 0.731_058_578_630_004_9],
+        );
+        assert_eq!(t.swish().to_vec(), t.silu().to_vec());
+        assert_close(
+            t.mish().to_vec(),
+            &[-0.303_401_461_374_108_95, 0.0, 0.865_098_388_267_310_3],
+        );
+    }
+
+    #[test]
+    fn backward_matches_finite_differences() {
+        let x = [-1.5, -0.3, 0.4, 2.0];
+        let input = Tensor::from(x.to_vec());
+        let ones = Tensor::from(vec![1.0; x.len()]);
+
+        for approximation in [GeluApproximation::None, GeluApproximation::Tanh] {
+            assert_close(
+                gelu_backward(&input, &ones, approximation)
+                    .unwrap()
+                    .to_vec(),
+                &numeric_gradient(&x, |t| t.gelu(approximation)),
+            );
+        }
+        assert_close(
+            silu_backward(&input, &ones).unwrap().to_vec(),
+            &numeric_gradient(&x, |t| t.silu()),
+        );
+        assert_close(
+            mish_backward(&input, &ones).unwrap().to_vec(),
+            &numeric_gradient(&x, |t| t.mish()),
+        );
+        assert_close(
+            elu_backward(&input, &ones, 0.5).unwrap().to_vec(),
+            &numeric_gradient(&x, |t| t.elu(0.5)),
+        );
+        assert_close(
+            leaky_relu_backward(&input, &ones, 0.1).unwrap().to_vec(),
+            &numeric_gradient(&x, |t| t.leaky_relu(0.1)),
+        );
+        assert_close(
+            hardtanh_backward(&input, &ones, -1.0, 1.0)
+                .unwrap()
+                .to_vec(),
+            &numeric_gradient(&x, |t| t.hardtanh(-1.0, 1.0)),
+        );
+    }
+
+    #[test]
+    fn softmax_is_shift_invariant_and_stable() {
+        let t = crate::tensor![[1.0, 2.0, 3.0], [1001.0, 1002.0, 1003.0]];
+
+        let rows = t.softmax(1).unwrap().to_vec();
+        let log = t.log_softmax(1).unwrap().to_vec();
+
+        assert_close(rows[3..].to_vec(), &rows[..3]);
+        assert!((rows[..3].iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert_close(log, &rows.iter().map(|p| p.ln()).collect::<Vec<_>>());
+        assert_eq!(
+            crate::tensor![[0.0, 5.0], [0.0, 5.0]]
+                .softmax(0)
+                .unwrap()
+                .to_vec(),
+            vec![0.5; 4]
+        );
+        assert!(t.softmax(2).is_err());
+    }
+}
@@ -0,0 +1,279 @@
+use crate::error::Result;
+use crate::types::Tensor;
+use num_traits::{Signed, Zero};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+impl<T: Add<Output = T> + Copy> Tensor<T> {
+    /// Elementwise sum of `self` and `rhs` after broadcasting them to a common shape.
+    ///
+    /// Fails with [`TensorError::BroadcastMismatch`](crate::error::TensorError::BroadcastMismatch)
+    /// if the shapes are incompatible. The result requires grad if either operand does.
+    pub fn try_add(&self, rhs: &Tensor<T>) -> Result<Tensor<T>> {
+        let mut result = self.zip_map(rhs, |left, right| left + right)?;
+        result.requires_grad = self.requires_grad || rhs.requires_grad;
+        Ok(result)
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> Tensor<T> {
+    /// Elementwise difference `self - rhs` after broadcasting them to a common shape.
+    ///
+    /// Fails with [`TensorError::BroadcastMismatch`](crate::error::TensorError::BroadcastMismatch)
+    /// if the shapes are incompatible. The result requires grad if either operand does.
+    pub fn try_sub(&self, rhs: &Tensor<T>) -> Result<Tensor<T>> {
+        let mut result = self.zip_map(rhs, |left, right| left - right)?;
+        result.requires_grad = self.requires_grad || rhs.requires_grad;
+        Ok(result)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Tensor<T> {
+    /// Elementwise (Hadamard) product of `self` and `rhs` after broadcasting them to a
+    /// common shape.
+    ///
+    /// Fails with [`TensorError::BroadcastMismatch`](crate::error::TensorError::BroadcastMismatch)
+    /// if the shapes are incompatible. The result requires grad if either operand does.
+    pub fn try_mul(&self, rhs: &Tensor<T>) -> Result<Tensor<T>> {
+        let mut result = self.zip_map(rhs, |left, right| left * right)?;
+        result.requires_grad = self.requires_grad || rhs.requires_grad;
+        Ok(result)
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Tensor<T> {
+    /// Elementwise quotient `self / rhs` after broadcasting them to a common shape.
+    ///
+    /// Fails with [`TensorError::BroadcastMismatch`](crate::error::TensorError::BroadcastMismatch)
+    /// if the shapes are incompatible. The result requires grad if either operand does.
+    pub fn try_div(&self, rhs: &Tensor<T>) -> Result<Tensor<T>> {
+        let mut result = self.zip_map(rhs, |left, right| left / right)?;
+        result.requires_grad = self.requires_grad || rhs.requires_grad;
+        Ok(result)
+    }
+}
+
+impl<T: Rem<Output = T> + Copy> Tensor<T> {
+    /// Elementwise remainder of truncated division, like Rust's `%` and C's `fmod`: the
+    /// result has the sign of `self`. Operands are broadcast to a common shape.
+    pub fn fmod(&self, rhs: &Tensor<T>) -> Result<Tensor<T>> {
+        let mut result = self.zip_map(rhs, |left, right| left % right)?;
+        result.requires_grad = self.requires_grad || rhs.requires_grad;
+        Ok(result)
+    }
+}
+
+impl<T: Rem<Output = T> + Add<Output = T> + PartialOrd + Zero + Copy> Tensor<T> {
+    /// Elementwise remainder of floored division, like Python's `%`: the result has the
+    /// sign of `rhs`, which makes it suited to wrapping values into a period. Operands are
+    /// broadcast to a common shape.
+    pub fn remainder(&self, rhs: &Tensor<T>) -> Result<Tensor<T>> {
+        let zero = T::zero();
+        let mut result = self.zip_map(rhs, |left, right| {
+            let r = left % right;
+            if r != zero && (r < zero) != (right < zero) {
+                r + right
+            } else {
+                r
+            }
+        })?;
+        result.requires_grad = self.requires_grad || rhs.requires_grad;
+        Ok(result)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul for Tensor<T> {
+    type Output = Tensor<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.try_mul(&rhs)
+            .unwrap_or_else(|e| panic!("Tensor multiplication failed: {}", e))
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Div for Tensor<T> {
+    type Output = Tensor<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.try_div(&rhs)
+            .unwrap_or_else(|e| panic!("Tensor division failed: {}", e))
+    }
+}
+
+impl<T: Rem<Output = T> + Copy> Rem for Tensor<T> {
+    type Output = Tensor<T>;
+
+    /// Same as [`Tensor::fmod`]; use [`Tensor::remainder`] for a result with the sign of `rhs`.
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.fmod(&rhs)
+            .unwrap_or_else(|e| panic!("Tensor remainder failed: {}", e))
+    }
+}
+
+impl<T: Add<Output = T> + Copy> Tensor<T> {
+    /// Adds `scalar` to every element, without materializing a constant tensor.
+    pub fn add_scalar(&self, scalar: T) -> Tensor<T> {
+        self.map(|value| value + scalar)
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> Tensor<T> {
+    /// Subtracts `scalar` from every element.
+    pub fn sub_scalar(&self, scalar: T) -> Tensor<T> {
+        self.map(|value| value - scalar)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Tensor<T> {
+    /// Multiplies every element by `scalar`.
+    pub fn mul_scalar(&self, scalar: T) -> Tensor<T> {
+        self.map(|value| value * scalar)
+    }
+}
+
+impl<T: Div<Output = T> + Copy> Tensor<T> {
+    /// Divides every element by `scalar`.
+    pub fn div_scalar(&self, scalar: T) -> Tensor<T> {
+        self.map(|value| value / scalar)
+    }
+}
+
+/// Implements `tensor op scalar` for every element type, and `scalar op tensor` for the
+/// listed primitive types (a generic `impl<T> Add<Tensor<T>> for T` is not allowed).
+macro_rules! impl_scalar_op {
+    ($trait:ident, $method:ident, $scalar_method:ident, $($scalar:ty),*) => {
+        impl<T: $trait<Output = T> + Copy> $trait<T> for Tensor<T> {
+            type Output = Tensor<T>;
+
+            fn $method(self, scalar: T) -> Self::Output {
+                self.$scalar_method(scalar)
+            }
+        }
+
+        $(
+            impl $trait<Tensor<$scalar>> for $scalar {
+                type Output = Tensor<$scalar>;
+
+                fn $method(self, tensor: Tensor<$scalar>) -> Self::Output {
+                    tensor.map(|value| $trait::$method(self, value))
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_op!(
+    Add, add, add_scalar, f32, f64, i8, i16, i32, i64, u8, u16, u32, u64
+);
+impl_scalar_op!(
+    Sub, sub, sub_scalar, f32, f64, i8, i16, i32, i64, u8, u16, u32, u64
+);
+impl_scalar_op!(
+    Mul, mul, mul_scalar, f32, f64, i8, i16, i32, i64, u8, u16, u32, u64
+);
+impl_scalar_op!(
+    Div, div, div_scalar, f32, f64, i8, i16, i32, i64, u8, u16, u32, u64
+);
+
+impl<T: Neg<Output = T> + Copy> Neg for Tensor<T> {
+    type Output = Tensor<T>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|value| -value)
+    }
+}
+
+impl<T: Signed + Copy> Tensor<T> {
+    /// Elementwise absolute value.
+    pub fn abs(&self) -> Tensor<T> {
+        self.map(|value| value.abs())
+    }
+
+    /// Elementwise sign: `-1`, `0` or `1` (for floats, `±1` keeps the sign of zero and
+    /// `NaN` stays `NaN`, as in [`f64::signum`]).
+    pub fn signum(&self) -> Tensor<T> {
+        self.map(|value| value.signum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    #[test]
+    fn try_add_and_try_sub() {
+        let a = crate::tensor![[1, 2], [3, 4]];
+        let b = Tensor::from(vec![10, 20]);
+
+        assert_eq!(a.try_add(&b).unwrap().to_vec(), vec![11, 22, 13, 24]);
+        assert_eq!(a.try_sub(&b).unwrap().to_vec(), vec![-9, -18, -7, -16]);
+    }
+
+    #[test]
+    fn try_add_reports_incompatible_shapes() {
+        let a = Tensor::from(vec![1, 2, 3]);
+        let b = Tensor::from(vec![1, 2]);
+
+        assert_eq!(
+            a.try_add(&b).err(),
+            Some(TensorError::BroadcastMismatch {
+                left: vec![3],
+                right: vec![2],
+            })
+        );
+    }
+
+    #[test]
+    fn mul_and_div_broadcast() {
+        let a = crate::tensor![[1.0, 2.0], [3.0, 4.0]];
+        let b = Tensor::from(vec![2.0, 4.0]);
+
+        assert_eq!((a.clone() * b.clone()).to_vec(), vec![2.0, 8.0, 6.0, 16.0]);
+        assert_eq!((a / b).to_vec(), vec![0.5, 0.5, 1.5, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be broadcast")]
+    fn mul_incompatible_shapes_panics() {
+        let _ = Tensor::from(vec![1, 2, 3]) * Tensor::from(vec![1, 2]);
+    }
+
+    #[test]
+    fn neg_abs_and_signum() {
+        let t = crate::tensor![[-2, 0], [3, -1]];
+
+        assert_eq!((-t.clone()).to_vec(), vec![2, 0, -3, 1]);
+        assert_eq!(t.abs().to_vec(), vec![2, 0, 3, 1]);
+        assert_eq!(t.signum().to_vec(), vec![-1, 0, 1, -1]);
+        assert_eq!(t.transpose(0, 1).unwrap().abs().to_vec(), vec![2, 3, 0, 1]);
+    }
+
+    #[test]
+    fn scalar_operators() {
+        let t = crate::tensor![[1.0, 2.0], [4.0, 8.0]];
+
+        assert_eq!((t.clone() + 1.0).to_vec(), vec![2.0, 3.0, 5.0, 9.0]);
+        assert_eq!((2.0f64 * t.clone()).to_vec(), vec![2.0, 4.0, 8.0, 16.0]);
+        assert_eq!((8.0f64 / t.clone()).to_vec(), vec![8.0, 4.0, 2.0, 1.0]);
+        assert_eq!((10.0f64 - t.clone()).to_vec(), vec![9.0, 8.0, 6.0, 2.0]);
+        assert_eq!(t.mul_scalar(0.5).to_vec(), vec![0.5, 1.0, 2.0, 4.0]);
+        assert_eq!(t.add_scalar(-1.0).shape(), &[2, 2]);
+    }
+
+    #[test]
+    fn fmod_and_remainder() {
+        let a = Tensor::from(vec![7, -7, 7, -7]);
+        let b = Tensor::from(vec![3, 3, -3, -3]);
+
+        assert_eq!(a.fmod(&b).unwrap().to_vec(), vec![1, -1, 1, -1]);
+        assert_eq!((a.clone() % b.clone()).to_vec(), vec![1, -1, 1, -1]);
+        assert_eq!(a.remainder(&b).unwrap().to_vec(), vec![1, 2, -2, -1]);
+        assert_eq!(
+            Tensor::from(vec![-0.5, 6.5])
+                .remainder(&Tensor::scalar(2.0))
+                .unwrap()
+                .to_vec(),
+            vec![1.5, 0.5]
+        );
+    }
+}
@@ -0,0 +1,173 @@
+//! Optional CBLAS backend for the GEMM kernel, enabled by the `blas` feature.
+//!
+//! The symbols are declared here and resolved against the CBLAS library that build.rs links,
+//! OpenBLAS unless `TENSOR_BLAS_LIB` names another (BLIS, Accelerate, ...).
+
+use std::any::TypeId;
+use std::os::raw::c_int;
+
+const ROW_MAJOR: c_int = 101;
+const NO_TRANS: c_int = 111;
+
+unsafe extern "C" {
+    fn cblas_sgemm(
+        layout: c_int,
+        trans_a: c_int,
+        trans_b: c_int,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: f32,
+        a: *const f32,
+        lda: c_int,
+        b: *const f32,
+        ldb: c_int,
+        beta: f32,
+        c: *mut f32,
+        ldc: c_int,
+    );
+
+    fn cblas_dgemm(
+        layout: c_int,
+        trans_a: c_int,
+        trans_b: c_int,
+        m: c_int,
+        n: c_int,
+        k: c_int,
+        alpha: f64,
+        a: *const f64,
+        lda: c_int,
+        b: *const f64,
+        ldb: c_int,
+        beta: f64,
+        c: *mut f64,
+        ldc: c_int,
+    );
+}
+
+/// Accumulates `alpha * (a x b)` into `out` through CBLAS when `T` is `f32` or `f64` and every
+/// dimension fits in a C `int`. Returns whether it did; otherwise nothing is touched.
+pub(crate) fn try_gemm<T: 'static>(
+    m: usize,
+    k: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    b: &[T],
+    out: &mut [T],
+) -> bool {
+    let (Ok(m), Ok(k), Ok(n)) = (c_int::try_from(m), c_int::try_from(k), c_int::try_from(n)) else {
+        return false;
+    };
+    // CBLAS rejects leading dimensions of zero even when the matrices are empty.
+    if m == 0 || k == 0 || n == 0 {
+        return false;
+    }
+
+    let alpha = &alpha as *const T;
+    let (a, b, out) = (a.as_ptr(), b.as_ptr(), out.as_mut_ptr());
+    // SAFETY: the type checks make the pointer casts identities, the slices hold row-major
+    // `m x k`, `k x n` and `m x n` matrices as the kernel's caller guarantees, and `out`
+    // does not alias the inputs because it is borrowed mutably.
+    if TypeId::of::<T>() == TypeId::of::<f32>() {
+        unsafe {
+            cblas_sgemm(
+                ROW_MAJOR,
+                NO_TRANS,
+                NO_TRANS,
+                m,
+                n,
+                k,
+                *alpha.cast::<f32>(),
+                a.cast(),
+                k,
+                b.cast(),
+                n,
+                1.0,
+                out.cast(),
+                n,
+            )
+        };
+        true
+    } else if TypeId::of::<T>() == TypeId::of::<f64>() {
+        unsafe {
+            cblas_dgemm(
+                ROW_MAJOR,
+                NO_TRANS,
+                NO_TRANS,
+                m,
+                n,
+                k,
+                *alpha.cast::<f64>(),
+                a.cast(),
+                k,
+                b.cast(),
+                n,
+                1.0,
+                out.cast(),
+                n,
+            )
+        };
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::try_gemm;
+    use crate::ops::matmul::tiled_gemm;
+    use crate::types::Tensor;
+
+    /// Runs both kernels on the same operands, accumulating into the same non-zero `out`, and
+    /// returns their results.
+    fn both<T: num_traits::Float + 'static>(m: usize, k: usize, n: usize) -> (Vec<T>, Vec<T>) {
+        let value = |i: usize| T::from((i * 7 % 13) as f64 - 6.0).unwrap();
+        let a = (0..m * k).map(value).collect::<Vec<_>>();
+        let b = (0..k * n).map(|i| value(i + 5)).collect::<Vec<_>>();
+        let out = (0..m * n).map(|i| value(i + 3)).collect::<Vec<_>>();
+        let alpha = T::from(0.5).unwrap();
+
+        let (mut blas, mut native) = (out.clone(), out);
+        assert!(try_gemm(m, k, n, alpha, &a, &b, &mut blas));
+        tiled_gemm(m, k, n, alpha, &a, &b, &mut native);
+        (blas, native)
+    }
+
+    #[test]
+    fn blas_gemm_matches_native() {
+        // Non-square and larger than one tile, so a mix-up of `m`, `n`, `k` or the leading
+        // dimensions shows.
+        for (m, k, n) in [(70, 65, 3), (1, 9, 130), (5, 1, 2)] {
+            let (blas, native) = both::<f64>(m, k, n);
+            assert_eq!(blas, native);
+            let (blas, native) = both::<f32>(m, k, n);
+            for (x, y) in blas.iter().zip(&native) {
+                assert!((x - y).abs() <= 1e-4 * y.abs().max(1.0), "{x} != {y}");
+            }
+        }
+        assert!(!try_gemm(2, 2, 2, 1i64, &[1; 4], &[1; 4], &mut [0; 4]));
+    }
+
+    #[test]
+    fn blas_addmm_accumulates_into_bias() {
+        let bias = crate::tensor![[1.0f32, -1.0, 0.5], [2.0, 0.0, -2.0]];
+        let a = crate::tensor![[1.0f32, 2.0], [3.0, 4.0]];
+        let b = crate::tensor![[1.0f32, 0.0, -1.0], [0.5, 2.0, 1.0]];
+
+        let result = bias.addmm(&a, &b, 2.0, 3.0).unwrap();
+
+        assert_eq!(result.to_vec(), vec![7.0, 5.0, 3.5, 16.0, 16.0, -4.0]);
+        let doubled = |t: &Tensor<f32>| {
+            let t = t.map(f64::from);
+            Tensor::stack(&[&t, &t], 0).unwrap()
+        };
+        let batched = doubled(&bias);
+        let (a, b) = (doubled(&a), doubled(&b));
+        assert_eq!(
+            batched.baddbmm(&a, &b, 2.0, 3.0).unwrap().to_vec(),
+            [7.0, 5.0, 3.5, 16.0, 16.0, -4.0].repeat(2)
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::error::Result;
+use crate::types::Tensor;
+use std::cmp::Ordering;
+
+/// Limits `x` to `[min, max]`, skipping a missing bound. `NaN` passes through unchanged,
+/// and if `min > max` every element ends up equal to `max`.
+pub(crate) fn clamp_value<T: PartialOrd + Copy>(x: T, min: Option<T>, max: Option<T>) -> T {
+    let x = match min {
+        Some(min) if x < min => min,
+        _ => x,
+    };
+    match max {
+        Some(max) if x > max => max,
+        _ => x,
+    }
+}
+
+impl<T: PartialOrd + Copy> Tensor<T> {
+    /// Limits every element to `[min, max]`; either bound may be `None`.
+    pub fn clamp(&self, min: Option<T>, max: Option<T>) -> Tensor<T> {
+        self.map(|x| clamp_value(x, min, max))
+    }
+
+    /// Raises every element below `min` to `min`.
+    pub fn clamp_min(&self, min: T) -> Tensor<T> {
+        self.clamp(Some(min), None)
+    }
+
+    /// Lowers every element above `max` to `max`.
+    pub fn clamp_max(&self, max: T) -> Tensor<T> {
+        self.clamp(None, Some(max))
+    }
+}
+
+impl<T: PartialOrd + Copy> Tensor<T> {
+    /// Elementwise larger of `self` and `other`, after broadcasting them to a common shape.
+    /// `NaN` in either operand propagates.
+    pub fn maximum(&self, other: &Tensor<T>) -> Result<Tensor<T>> {
+        self.pick_elementwise(other, Ordering::Greater)
+    }
+
+    /// Elementwise smaller of `self` and `other`, after broadcasting them to a common shape.
+    /// `NaN` in either operand propagates.
+    pub fn minimum(&self, other: &Tensor<T>) -> Result<Tensor<T>> {
+        self.pick_elementwise(other, Ordering::Less)
+    }
+
+    /// Keeps the left element when it compares as `keep_left` against the right one (or
+    /// is incomparable with itself, i.e. `NaN`), and the right element otherwise.
+    fn pick_elementwise(&self, other: &Tensor<T>, keep_left: Ordering) -> Result<Tensor<T>> {
+        let mut result = self.zip_map(other, |left, right| {
+            let left_is_nan = left.partial_cmp(&left).is_none();
+            match left.partial_cmp(&right) {
+                Some(ordering) if ordering == keep_left => left,
+                None if left_is_nan => left,
+                _ => right,
+            }
+        })?;
+        result.requires_grad = self.requires_grad || other.requires_grad;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::Tensor;
+
+    #[test]
+    fn clamp_with_optional_bounds() {
+        let t = Tensor::from(vec![-2.0, 0.5, 3.0, f64::NAN]);
+
+        let both = t.clamp(Some(0.0), Some(1.0)).to_vec();
+
+        assert_eq!(both[..3], [0.0, 0.5, 1.0]);
+        assert!(both[3].is_nan());
+        assert_eq!(t.clamp_min(0.0).to_vec()[..3], [0.0, 0.5, 3.0]);
+        assert_eq!(t.clamp_max(0.0).to_vec()[..3], [-2.0, 0.0, 0.0]);
+        assert_eq!(t.clamp(None, None).to_vec()[..3], [-2.0, 0.5, 3.0]);
+    }
+
+    #[test]
+    fn maximum_and_minimum() {
+        let a = crate::tensor![[1.0, 5.0], [f64::NAN, 0.0]];
+        let b = Tensor::from(vec![2.0, f64::NAN]);
+
+        let max = a.maximum(&b).unwrap().to_vec();
+        let min = a.minimum(&b).unwrap().to_vec();
+
+        assert_eq!(max[0], 2.0);
+        assert_eq!(min[0], 1.0);
+        assert!(max[1].is_nan() && max[2].is_nan() && min[3].is_nan());
+        assert_eq!(
+            Tensor::from(vec![3, -1])
+                .maximum(&Tensor::scalar(0))
+                .unwrap()
+                .to_vec(),
+            vec![3, 0]
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use crate::error::Result;
+use crate::types::Tensor;
+
+/// Defines a broadcasting tensor-tensor comparison and its tensor-scalar counterpart.
+macro_rules! comparison {
+    ($bound:ident, $method:ident, $scalar_method:ident, $op:tt) => {
+        impl<T: $bound + Copy> Tensor<T> {
+            #[doc = concat!(
+                "Elementwise `self ", stringify!($op), " other` as a boolean mask, after ",
+                "broadcasting both to a common shape."
+            )]
+            pub fn $method(&self, other: &Tensor<T>) -> Result<Tensor<bool>> {
+                self.zip_map(other, |left, right| left $op right)
+            }
+
+            #[doc = concat!("Elementwise `self ", stringify!($op), " value` as a boolean mask.")]
+            pub fn $scalar_method(&self, value: T) -> Tensor<bool> {
+                let mut mask = self.map(|x| x $op value);
+                mask.requires_grad = false;
+                mask
+            }
+        }
+    };
+}
+
+comparison!(PartialEq, eq, eq_scalar, ==);
+comparison!(PartialEq, ne, ne_scalar, !=);
+comparison!(PartialOrd, lt, lt_scalar, <);
+comparison!(PartialOrd, le, le_scalar, <=);
+comparison!(PartialOrd, gt, gt_scalar, >);
+comparison!(PartialOrd, ge, ge_scalar, >=);
+
+#[cfg(test)]
+mod tests {
+    use crate::types::Tensor;
+
+    #[test]
+    fn tensor_comparisons_broadcast() {
+        let a = crate::tensor![[1, 2, 3], [4, 5, 6]];
+        let b = Tensor::from(vec![2, 2, 7]);
+
+        assert_eq!(
+            a.eq(&b).unwrap().to_vec(),
+            vec![false, true, false, false, false, false]
+        );
+        assert_eq!(
+            a.ne(&b).unwrap().to_vec(),
+            vec![true, false, true, true, true, true]
+        );
+        assert_eq!(
+            a.lt(&b).unwrap().to_vec(),
+            vec![true, false, true, false, false, true]
+        );
+        assert_eq!(a.ge(&b).unwrap().shape(), &[2, 3]);
+        assert!(a.gt(&Tensor::from(vec![1, 2])).is_err());
+    }
+
+    #[test]
+    fn scalar_comparisons() {
+        let t = Tensor::from(vec![1.0, 2.0, f64::NAN]);
+
+        assert_eq!(t.le_scalar(2.0).to_vec(), vec![true, true, false]);
+        assert_eq!(t.gt_scalar(1.0).to_vec(), vec![false, true, false]);
+        assert_eq!(t.eq_scalar(1.0).to_vec(), vec![true, false, false]);
+        assert_eq!(t.ne_scalar(1.0).to_vec(), vec![false, true, true]);
+        assert_eq!(t.ge_scalar(2.0).to_vec(), vec![false, true, false]);
+        assert_eq!(t.lt_scalar(2.0).to_vec(), vec![true, false, false]);
+    }
+}
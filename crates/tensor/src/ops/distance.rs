@@ -0,0 +1,170 @@
+use crate::error::{Result, TensorError};
+use crate::reduce::lane_norm;
+use crate::types::Tensor;
+use crate::view::broadcast_shapes;
+use num_traits::Float;
+
+/// Norms below this are raised to it in [`Tensor::cosine_similarity`], so zero vectors give
+/// a similarity of zero instead of `NaN`.
+const COSINE_EPS: f64 = 1e-8;
+
+impl<T: Float> Tensor<T> {
+    /// Cosine of the angle between `self` and `other` along `dim`, after broadcasting them to
+    /// a common shape, which loses `dim`. Each norm is clamped below at `1e-8`.
+    pub fn cosine_similarity(&self, other: &Tensor<T>, dim: usize) -> Result<Tensor<T>> {
+        let eps = T::from(COSINE_EPS).unwrap();
+        let products = self.zip_map(other, |x, y| (x * y, x * x, y * y))?;
+        let mut result = products.reduce_dims(&[dim], false, |lane| {
+            let (dot, x, y) = lane.iter().fold(
+                (T::zero(), T::zero(), T::zero()),
+                |(dot, x, y), &(xy, xx, yy)| (dot + xy, x + xx, y + yy),
+            );
+            dot / (x.sqrt().max(eps) * y.sqrt().max(eps))
+        })?;
+        result.requires_grad = self.requires_grad || other.requires_grad;
+        Ok(result)
+    }
+}
+
+/// Pairwise `p`-norm distances between the rows of `a` and the rows of `b`.
+///
+/// `a` is `[..., P, M]` and `b` is `[..., R, M]` with broadcastable batch dimensions; the
+/// result is `[..., P, R]`. For `p = 2` the distances come from the expansion
+/// `|x - y|^2 = |x|^2 + |y|^2 - 2 x.y`, so the bulk of the work is one matrix product and no
+/// `P x R x M` difference tensor is formed. Other `p` go row pair by row pair.
+///
+/// Fails with [`TensorError::ArgumentOutOfRange`] if `p` is negative or `NaN`.
+pub fn cdist<T: Float + 'static>(a: &Tensor<T>, b: &Tensor<T>, p: T) -> Result<Tensor<T>> {
+    if p.is_nan() || p < T::zero() {
+        return Err(TensorError::ArgumentOutOfRange {
+            name: "p",
+            range: "[0, inf]",
+            value: p.to_f64().unwrap_or(f64::NAN).to_string(),
+        });
+    }
+    for t in [a, b] {
+        if t.ndim() < 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: t.ndim(),
+            });
+        }
+    }
+    let (a_rank, b_rank) = (a.ndim(), b.ndim());
+    let features = a.shape()[a_rank - 1];
+    if b.shape()[b_rank - 1] != features {
+        return Err(TensorError::ShapeMismatch {
+            left: a.shape().to_vec(),
+            right: b.shape().to_vec(),
+        });
+    }
+
+    let mut result = if p == T::from(2.0).unwrap() {
+        let squared_norms = |t: &Tensor<T>| t.map(|x| x * x).sum_dim(&[t.ndim() - 1], true);
+        let a_norms = squared_norms(a)?;
+        let b_norms = squared_norms(b)?.transpose(b_rank - 2, b_rank - 1)?;
+        let products = a.try_matmul(&b.transpose(b_rank - 2, b_rank - 1)?)?;
+        // Rounding can leave a slightly negative square for (near-)identical rows.
+        a_norms.broadcast_zip3(&b_norms, &products, |x, y, xy| {
+            (x + y - (xy + xy)).max(T::zero()).sqrt()
+        })?
+    } else {
+        let batch = broadcast_shapes(&a.shape()[..a_rank - 2], &b.shape()[..b_rank - 2])?;
+        let (rows, cols) = (a.shape()[a_rank - 2], b.shape()[b_rank - 2]);
+        let expand = |t: &Tensor<T>, rows: usize| {
+            let mut shape = batch.clone();
+            shape.extend([rows, features]);
+            t.broadcast_to(&shape).map(|t| t.to_vec())
+        };
+        let (a_data, b_data) = (expand(a, rows)?, expand(b, cols)?);
+
+        let mut distances = Vec::with_capacity(batch.iter().product::<usize>() * rows * cols);
+        let mut difference = vec![T::zero(); features];
+        for index in 0..batch.iter().product() {
+            let a_rows = &a_data[index * rows * features..(index + 1) * rows * features];
+            let b_rows = &b_data[index * cols * features..(index + 1) * cols * features];
+            for i in 0..rows {
+                let x = &a_rows[i * features..(i + 1) * features];
+                for j in 0..cols {
+                    let y = &b_rows[j * features..(j + 1) * features];
+                    for (d, (&x, &y)) in difference.iter_mut().zip(x.iter().zip(y)) {
+                        *d = x - y;
+                    }
+                    distances.push(lane_norm(&difference, p));
+                }
+            }
+        }
+        let mut shape = batch;
+        shape.extend([rows, cols]);
+        Tensor::from_vec(distances, &shape)
+    };
+    result.requires_grad = a.requires_grad || b.requires_grad;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cdist;
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    fn assert_close(actual: &Tensor<f64>, expected: &Tensor<f64>) {
+        assert_eq!(actual.shape(), expected.shape());
+        for (a, e) in actual.to_vec().iter().zip(expected.to_vec()) {
+            assert!((a - e).abs() < 1e-10, "{a} != {e}");
+        }
+    }
+
+    #[test]
+    fn cosine_similarity() {
+        let a = crate::tensor![[1.0, 0.0], [1.0, 1.0], [0.0, 0.0]];
+        let b = Tensor::from(vec![2.0, 0.0]);
+
+        assert_close(
+            &a.cosine_similarity(&b, 1).unwrap(),
+            &Tensor::from(vec![1.0, 0.5f64.sqrt(), 0.0]),
+        );
+        assert_close(
+            &a.cosine_similarity(&a.mul_scalar(-3.0), 0).unwrap(),
+            &Tensor::from(vec![-1.0, -1.0]),
+        );
+        assert!(a.cosine_similarity(&b, 2).is_err());
+    }
+
+    #[test]
+    fn cdist_matches_direct_norms() {
+        let a = crate::tensor![[0.0, 0.0], [1.0, 1.0], [3.0, -1.0]];
+        let b = crate::tensor![[3.0, 4.0], [1.0, 1.0]];
+
+        let euclidean = cdist(&a, &b, 2.0).unwrap();
+
+        assert_close(
+            &euclidean,
+            &crate::tensor![
+                [5.0, 2.0f64.sqrt()],
+                [13.0f64.sqrt(), 0.0],
+                [5.0, 8.0f64.sqrt()]
+            ],
+        );
+        assert_close(
+            &cdist(&a, &b, 1.0).unwrap(),
+            &crate::tensor![[7.0, 2.0], [5.0, 0.0], [5.0, 4.0]],
+        );
+        assert_close(
+            &cdist(&a, &b, f64::INFINITY).unwrap(),
+            &crate::tensor![[4.0, 1.0], [3.0, 0.0], [5.0, 2.0]],
+        );
+        let batched = Tensor::stack(&[&a, &a], 0).unwrap();
+        assert_close(
+            &cdist(&batched, &b, 2.0).unwrap(),
+            &Tensor::stack(&[&euclidean, &euclidean], 0).unwrap(),
+        );
+        assert_eq!(cdist(&batched, &b, 1.0).unwrap().shape(), &[2, 3, 2]);
+        assert!(cdist(&a, &Tensor::zeros(&[2, 3]), 2.0).is_err());
+        assert!(matches!(
+            cdist(&a, &b, -1.0),
+            Err(TensorError::ArgumentOutOfRange { name: "p", .. })
+        ));
+        assert!(cdist(&a, &b, f64::NAN).is_err());
+    }
+}
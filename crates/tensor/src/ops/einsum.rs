@@ -0,0 +1,292 @@
+use crate::error::{Result, TensorError};
+use crate::shape::Shape;
+use crate::types::{BaseTensor, Tensor};
+use num_traits::{One, Zero};
+use std::collections::HashMap;
+use std::ops::Mul;
+
+/// An operand paired with the label of each of its dimensions.
+struct Term<T> {
+    tensor: Tensor<T>,
+    labels: Vec<char>,
+}
+
+/// Evaluates an Einstein summation `equation` such as `"ij,jk->ik"` over `operands`.
+///
+/// Each comma-separated input term labels the dimensions of one operand with letters.
+/// Labels missing from the output after `->` are summed over; without `->` the output
+/// holds the labels used exactly once, in alphabetical order. A label repeated within one
+/// term takes the diagonal, so `"ii->"` is the trace.
+///
+/// The plan sums away each label that only one operand uses, then contracts the operands
+/// left to right, each pair as a single batched [`Tensor::try_matmul`].
+pub fn einsum<T: Zero + One + Mul<Output = T> + Copy + 'static>(
+    equation: &str,
+    operands: &[&Tensor<T>],
+) -> Result<Tensor<T>> {
+    let invalid = |reason: &str| TensorError::InvalidEinsum {
+        equation: equation.to_string(),
+        reason: reason.to_string(),
+    };
+    let compact = equation.replace(' ', "");
+    let (inputs, output) = match compact.split_once("->") {
+        Some((inputs, output)) => (inputs, Some(output)),
+        None => (compact.as_str(), None),
+    };
+    let inputs = inputs.split(',').collect::<Vec<_>>();
+    if inputs.len() != operands.len() {
+        return Err(invalid(
+            "the number of terms does not match the number of operands",
+        ));
+    }
+    if !inputs
+        .iter()
+        .chain(&output)
+        .all(|term| term.chars().all(|c| c.is_ascii_alphabetic()))
+    {
+        return Err(invalid("labels must be ASCII letters"));
+    }
+
+    let mut sizes = HashMap::new();
+    let mut terms = Vec::with_capacity(operands.len());
+    for (input, operand) in inputs.iter().zip(operands) {
+        let labels = input.chars().collect::<Vec<_>>();
+        if labels.len() != operand.ndim() {
+            return Err(invalid("a term has a different rank than its operand"));
+        }
+        for (&label, &size) in labels.iter().zip(operand.shape()) {
+            if *sizes.entry(label).or_insert(size) != size {
+                return Err(invalid("a label is used for dimensions of different sizes"));
+            }
+        }
+        terms.push(take_diagonals(operand, labels));
+    }
+
+    let output = match output {
+        Some(output) => output.chars().collect::<Vec<_>>(),
+        None => {
+            let mut once = sizes
+                .keys()
+                .copied()
+                .filter(|&label| {
+                    inputs
+                        .iter()
+                        .flat_map(|i| i.chars())
+                        .filter(|&c| c == label)
+                        .count()
+                        == 1
+                })
+                .collect::<Vec<_>>();
+            once.sort_unstable();
+            once
+        }
+    };
+    for (i, label) in output.iter().enumerate() {
+        if !sizes.contains_key(label) {
+            return Err(invalid("an output label does not appear in any input"));
+        }
+        if output[..i].contains(label) {
+            return Err(invalid("an output label is repeated"));
+        }
+    }
+
+    // Sum away labels that no other operand and not the output needs.
+    for i in 0..terms.len() {
+        let needed = |label: &char| {
+            output.contains(label)
+                || terms
+                    .iter()
+                    .enumerate()
+                    .any(|(j, term)| j != i && term.labels.contains(label))
+        };
+        let (kept, summed) =
+            (0..terms[i].labels.len()).partition::<Vec<_>, _>(|&d| needed(&terms[i].labels[d]));
+        if !summed.is_empty() {
+            let term = &terms[i];
+            let tensor = term.tensor.sum_dim(&summed, false)?;
+            let labels = kept.iter().map(|&d| term.labels[d]).collect();
+            terms[i] = Term { tensor, labels };
+        }
+    }
+
+    let mut terms = terms.into_iter();
+    let Some(mut result) = terms.next() else {
+        return Err(TensorError::EmptyInput);
+    };
+    let rest = terms.collect::<Vec<_>>();
+    for (i, right) in rest.iter().enumerate() {
+        let later = rest[i + 1..]
+            .iter()
+            .flat_map(|term| &term.labels)
+            .chain(&output)
+            .copied()
+            .collect::<Vec<_>>();
+        result = contract(&result, right, &later, &sizes)?;
+    }
+
+    // The remaining labels are exactly the output labels, possibly in another order.
+    let order = output
+        .iter()
+        .map(|label| result.labels.iter().position(|l| l == label).unwrap())
+        .collect::<Vec<_>>();
+    let mut result = result.tensor.permute(&order)?.contiguous();
+    result.requires_grad = operands.iter().any(|t| t.requires_grad);
+    Ok(result)
+}
+
+/// Collapses repeated labels of one operand into a single dimension, viewing the diagonal
+/// by adding the strides of the repeated dimensions.
+fn take_diagonals<T>(tensor: &Tensor<T>, labels: Vec<char>) -> Term<T> {
+    let mut unique = Vec::new();
+    let mut shape = Vec::new();
+    let mut strides = Vec::new();
+    for (d, &label) in labels.iter().enumerate() {
+        match unique.iter().position(|&l| l == label) {
+            Some(i) => strides[i] += tensor.strides()[d],
+            None => {
+                unique.push(label);
+                shape.push(tensor.shape()[d]);
+                strides.push(tensor.strides()[d]);
+            }
+        }
+    }
+
+    let view = BaseTensor {
+        shape: Shape::from(shape),
+        strides,
+        ..tensor.base.share()
+    }
+    .into_tensor(tensor.requires_grad);
+    Term {
+        tensor: view,
+        labels: unique,
+    }
+}
+
+/// Contracts two terms over the labels they share that `later` does not need, as one
+/// batched matrix product over the shared labels that are still needed.
+fn contract<T: Zero + One + Mul<Output = T> + Copy + 'static>(
+    left: &Term<T>,
+    right: &Term<T>,
+    later: &[char],
+    sizes: &HashMap<char, usize>,
+) -> Result<Term<T>> {
+    let shared = |label: &&char| right.labels.contains(label);
+    let batch = left
+        .labels
+        .iter()
+        .filter(|l| shared(l) && later.contains(l))
+        .copied()
+        .collect::<Vec<_>>();
+    let summed = left
+        .labels
+        .iter()
+        .filter(|l| shared(l) && !later.contains(l))
+        .copied()
+        .collect::<Vec<_>>();
+    let left_only = left
+        .labels
+        .iter()
+        .filter(|l| !shared(l))
+        .copied()
+        .collect::<Vec<_>>();
+    let right_only = right
+        .labels
+        .iter()
+        .filter(|l| !left.labels.contains(l))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let size = |labels: &[char]| labels.iter().map(|l| sizes[l]).product::<usize>() as isize;
+    let arrange = |term: &Term<T>, groups: [&[char]; 3]| {
+        let order = groups
+            .concat()
+            .iter()
+            .map(|label| term.labels.iter().position(|l| l == label).unwrap())
+            .collect::<Vec<_>>();
+        let shape = groups.map(size);
+        term.tensor.permute(&order)?.reshape(&shape)
+    };
+    let product = arrange(left, [&batch, &left_only, &summed])?
+        .try_matmul(&arrange(right, [&batch, &summed, &right_only])?)?;
+
+    let labels = [batch, left_only, right_only].concat();
+    let shape = labels.iter().map(|l| sizes[l] as isize).collect::<Vec<_>>();
+    Ok(Term {
+        tensor: product.reshape(&shape)?,
+        labels,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::einsum;
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    #[test]
+    fn einsum_contractions() {
+        let a = crate::tensor![[1, 2, 3], [4, 5, 6]];
+        let b = crate::tensor![[1, 0], [0, 1], [1, 1]];
+        let square = crate::tensor![[1, 2], [3, 4]];
+
+        assert_eq!(
+            einsum("ij,jk->ik", &[&a, &b]).unwrap().to_vec(),
+            a.matmul(&b).to_vec()
+        );
+        assert_eq!(
+            einsum("ij->ji", &[&a]).unwrap().to_vec(),
+            vec![1, 4, 2, 5, 3, 6]
+        );
+        assert_eq!(einsum("ii->", &[&square]).unwrap().to_vec(), vec![5]);
+        assert_eq!(einsum("ii->i", &[&square]).unwrap().to_vec(), vec![1, 4]);
+        assert_eq!(einsum("ij->j", &[&a]).unwrap().to_vec(), vec![5, 7, 9]);
+        assert_eq!(einsum("ij,ij->", &[&a, &a]).unwrap().to_vec(), vec![91]);
+        assert_eq!(einsum("jk,ij", &[&b, &a]).unwrap().shape(), &[2, 2]);
+    }
+
+    #[test]
+    fn einsum_batched_and_chained() {
+        let x = Tensor::arange(0, 12, 1).reshape(&[2, 2, 3]).unwrap();
+        let y = Tensor::arange(0, 6, 1).reshape(&[3, 2]).unwrap();
+        let z = Tensor::from(vec![1, -1]);
+
+        let batched = einsum("bij,jk->bki", &[&x, &y]).unwrap();
+        let chained = einsum("bij,jk,k->bi", &[&x, &y, &z]).unwrap();
+        let expected = x.matmul(&y).matmul(&z);
+
+        assert_eq!(batched.shape(), &[2, 2, 2]);
+        assert_eq!(
+            batched.to_vec(),
+            x.matmul(&y).transpose(1, 2).unwrap().contiguous().to_vec()
+        );
+        assert_eq!(chained.to_vec(), expected.to_vec());
+        assert!(!chained.requires_grad);
+
+        let mut y = y.map(|v| v as f64);
+        y.requires_grad = true;
+        let x = x.map(|v| v as f64);
+        assert!(einsum("bij,jk->bik", &[&x, &y]).unwrap().requires_grad);
+        assert!(!einsum("bij->", &[&x]).unwrap().requires_grad);
+    }
+
+    #[test]
+    fn einsum_rejects_bad_equations() {
+        let a = crate::tensor![[1, 2], [3, 4]];
+        let v = Tensor::from(vec![1, 2, 3]);
+
+        assert!(einsum("ij,jk->ik", &[&a]).is_err());
+        assert!(einsum("ijk->i", &[&a]).is_err());
+        assert!(einsum("ij,j->i", &[&a, &v]).is_err());
+        assert!(einsum("ij->ix", &[&a]).is_err());
+        assert!(einsum("ij->ii", &[&a]).is_err());
+        assert!(einsum("i.j->i", &[&a]).is_err());
+        let w = Tensor::from(vec![5, 6]);
+        assert!(matches!(
+            einsum("ij,j>->i", &[&a, &w]),
+            Err(TensorError::InvalidEinsum { .. })
+        ));
+        assert!(einsum("ij->i->j", &[&a]).is_err());
+        assert!(einsum("i-,j->i", &[&w, &w]).is_err());
+    }
+}
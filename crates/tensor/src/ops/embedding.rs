@@ -0,0 +1,127 @@
+use crate::error::{Result, TensorError};
+use crate::indexing::check_index;
+use crate::types::Tensor;
+use num_traits::Zero;
+use std::ops::Add;
+
+/// Looks up the rows of the 2-D `weight` table at `indices`.
+///
+/// The result has shape `indices.shape() + [weight.shape()[1]]`. `padding_idx` does not
+/// change the lookup itself; it only marks a row whose gradient is kept at zero by
+/// [`embedding_backward`], but it must still name a row of `weight`.
+pub fn embedding<T: Clone>(
+    weight: &Tensor<T>,
+    indices: &Tensor<i64>,
+    padding_idx: Option<usize>,
+) -> Result<Tensor<T>> {
+    if weight.ndim() != 2 {
+        return Err(TensorError::RankMismatch {
+            expected: 2,
+            actual: weight.ndim(),
+        });
+    }
+    if let Some(padding_idx) = padding_idx {
+        check_index(padding_idx as i64, weight.shape()[0])?;
+    }
+
+    let rows = weight.index_select(0, &indices.reshape(&[-1])?)?;
+    let mut shape = indices
+        .shape()
+        .iter()
+        .map(|&d| d as isize)
+        .collect::<Vec<_>>();
+    shape.push(weight.shape()[1] as isize);
+    let mut result = rows.reshape(&shape)?;
+    result.requires_grad = weight.requires_grad;
+    Ok(result)
+}
+
+/// Gradient of [`embedding`] with respect to its weight table of `num_embeddings` rows.
+///
+/// The gradient rows of repeated indices are summed, and the row at `padding_idx` stays
+/// zero. `grad_output` must have shape `indices.shape() + [embedding_dim]`.
+pub fn embedding_backward<T: Add<Output = T> + Copy + Zero>(
+    indices: &Tensor<i64>,
+    grad_output: &Tensor<T>,
+    num_embeddings: usize,
+    padding_idx: Option<usize>,
+) -> Result<Tensor<T>> {
+    if grad_output.ndim() != indices.ndim() + 1 {
+        return Err(TensorError::RankMismatch {
+            expected: indices.ndim() + 1,
+            actual: grad_output.ndim(),
+        });
+    }
+    let (&dim, leading) = grad_output
+        .shape()
+        .split_last()
+        .expect("grad_output has at least one dimension");
+    if leading != indices.shape() {
+        return Err(TensorError::ShapeMismatch {
+            left: indices.shape().to_vec(),
+            right: grad_output.shape().to_vec(),
+        });
+    }
+
+    // Checked up front: with `dim == 0` there are no gradient rows to pair them with.
+    let positions = indices
+        .to_vec()
+        .into_iter()
+        .map(|index| check_index(index, num_embeddings))
+        .collect::<Result<Vec<_>>>()?;
+    let grad = grad_output.to_vec();
+    let mut grad_weight = vec![T::zero(); num_embeddings * dim];
+    for (row, position) in grad.chunks(dim.max(1)).zip(positions) {
+        if Some(position) == padding_idx {
+            continue;
+        }
+        let target = &mut grad_weight[position * dim..(position + 1) * dim];
+        for (slot, &g) in target.iter_mut().zip(row) {
+            *slot = *slot + g;
+        }
+    }
+    Ok(Tensor::from_vec(grad_weight, &[num_embeddings, dim]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{embedding, embedding_backward};
+    use crate::types::Tensor;
+
+    #[test]
+    fn embedding_gathers_rows() {
+        let weight = crate::tensor![[0.0, 0.0], [1.0, 2.0], [3.0, 4.0]];
+        let indices = crate::tensor![[2i64, 1], [0, 2]];
+
+        let out = embedding(&weight, &indices, Some(0)).unwrap();
+
+        assert_eq!(out.shape(), &[2, 2, 2]);
+        assert_eq!(out.to_vec(), vec![3.0, 4.0, 1.0, 2.0, 0.0, 0.0, 3.0, 4.0]);
+        assert!(embedding(&weight, &indices, Some(3)).is_err());
+        assert!(!out.requires_grad);
+
+        let mut weight = weight;
+        weight.requires_grad = true;
+        assert!(embedding(&weight, &indices, None).unwrap().requires_grad);
+    }
+
+    #[test]
+    fn embedding_backward_accumulates_and_skips_padding() {
+        let indices = Tensor::from(vec![2i64, 0, 2]);
+        let grad_output = crate::tensor![[1.0, 1.0], [5.0, 5.0], [2.0, 3.0]];
+
+        let grad = embedding_backward(&indices, &grad_output, 3, Some(0)).unwrap();
+
+        assert_eq!(grad.shape(), &[3, 2]);
+        assert_eq!(grad.to_vec(), vec![0.0, 0.0, 0.0, 0.0, 3.0, 4.0]);
+        assert!(embedding_backward(&indices, &Tensor::from(vec![1.0]), 3, None).is_err());
+        assert!(embedding_backward(&indices, &grad_output, 2, None).is_err());
+        assert!(embedding_backward(&indices, &Tensor::<f64>::zeros(&[3, 0]), 2, None).is_err());
+        assert_eq!(
+            embedding_backward(&indices, &Tensor::<f64>::zeros(&[3, 0]), 3, None)
+                .unwrap()
+                .shape(),
+            &[3, 0]
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use crate::error::Result;
+use crate::types::Tensor;
+use std::ops::{Add, Div, Mul, Sub};
+
+impl<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy> Tensor<T> {
+    /// Linear interpolation `self + weight * (end - self)`, computed in one pass after
+    /// broadcasting `self` and `end` to a common shape.
+    pub fn lerp(&self, end: &Tensor<T>, weight: T) -> Result<Tensor<T>> {
+        let mut result = self.zip_map(end, |start, end| start + weight * (end - start))?;
+        result.requires_grad = self.requires_grad || end.requires_grad;
+        Ok(result)
+    }
+}
+
+impl<T: Add<Output = T> + Mul<Output = T> + Copy> Tensor<T> {
+    /// Fused `self + value * a * b`, computed in one pass after broadcasting all three
+    /// tensors to a common shape.
+    pub fn addcmul(&self, a: &Tensor<T>, b: &Tensor<T>, value: T) -> Result<Tensor<T>> {
+        let mut result = self.broadcast_zip3(a, b, |x, a, b| x + value * a * b)?;
+        result.requires_grad = self.requires_grad || a.requires_grad || b.requires_grad;
+        Ok(result)
+    }
+}
+
+impl<T: Add<Output = T> + Mul<Output = T> + Div<Output = T> + Copy> Tensor<T> {
+    /// Fused `self + value * a / b`, computed in one pass after broadcasting all three
+    /// tensors to a common shape.
+    pub fn addcdiv(&self, a: &Tensor<T>, b: &Tensor<T>, value: T) -> Result<Tensor<T>> {
+        let mut result = self.broadcast_zip3(a, b, |x, a, b| x + value * a / b)?;
+        result.requires_grad = self.requires_grad || a.requires_grad || b.requires_grad;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::Tensor;
+
+    #[test]
+    fn lerp() {
+        let start = Tensor::from(vec![0.0, 10.0]);
+        let end = Tensor::from(vec![4.0, 20.0]);
+
+        assert_eq!(start.lerp(&end, 0.25).unwrap().to_vec(), vec![1.0, 12.5]);
+        assert_eq!(
+            start.lerp(&Tensor::scalar(2.0), 1.0).unwrap().to_vec(),
+            vec![2.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn addcmul_and_addcdiv_broadcast() {
+        let x = Tensor::from(vec![1.0, 1.0]);
+        let a = crate::tensor![[2.0], [4.0]];
+        let b = Tensor::from(vec![1.0, 2.0]);
+
+        let mul = x.addcmul(&a, &b, 0.5).unwrap();
+        let div = x.addcdiv(&a, &b, 0.5).unwrap();
+
+        assert_eq!(mul.shape(), &[2, 2]);
+        assert_eq!(mul.to_vec(), vec![2.0, 3.0, 3.0, 5.0]);
+        assert_eq!(div.to_vec(), vec![2.0, 1.5, 3.0, 2.0]);
+        assert!(
+            x.addcmul(&Tensor::from(vec![1.0, 2.0, 3.0]), &b, 1.0)
+                .is_err()
+        );
+    }
+}
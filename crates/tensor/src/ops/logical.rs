@@ -0,0 +1,93 @@
+use crate::error::Result;
+use crate::types::Tensor;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+impl Tensor<bool> {
+    /// Elementwise AND of two masks, after broadcasting them to a common shape.
+    pub fn logical_and(&self, other: &Tensor<bool>) -> Result<Tensor<bool>> {
+        self.zip_map(other, |left, right| left && right)
+    }
+
+    /// Elementwise OR of two masks, after broadcasting them to a common shape.
+    pub fn logical_or(&self, other: &Tensor<bool>) -> Result<Tensor<bool>> {
+        self.zip_map(other, |left, right| left || right)
+    }
+
+    /// Elementwise exclusive OR of two masks, after broadcasting them to a common shape.
+    pub fn logical_xor(&self, other: &Tensor<bool>) -> Result<Tensor<bool>> {
+        self.zip_map(other, |left, right| left != right)
+    }
+
+    /// Elementwise negation of a mask.
+    pub fn logical_not(&self) -> Tensor<bool> {
+        self.map(|value| !value)
+    }
+}
+
+/// Implements a binary operator on masks by delegating to the checked method.
+macro_rules! impl_logical_op {
+    ($trait:ident, $method:ident, $logical:ident) => {
+        impl $trait for Tensor<bool> {
+            type Output = Tensor<bool>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$logical(&rhs).unwrap_or_else(|e| {
+                    panic!(concat!("Tensor ", stringify!($logical), " failed: {}"), e)
+                })
+            }
+        }
+    };
+}
+
+impl_logical_op!(BitAnd, bitand, logical_and);
+impl_logical_op!(BitOr, bitor, logical_or);
+impl_logical_op!(BitXor, bitxor, logical_xor);
+
+impl Not for Tensor<bool> {
+    type Output = Tensor<bool>;
+
+    fn not(self) -> Self::Output {
+        self.logical_not()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::Tensor;
+
+    #[test]
+    fn logical_ops_broadcast() {
+        let a = crate::tensor![[true, false], [true, true]];
+        let b = Tensor::from(vec![true, false]);
+
+        assert_eq!(
+            a.logical_and(&b).unwrap().to_vec(),
+            vec![true, false, true, false]
+        );
+        assert_eq!(
+            a.logical_or(&b).unwrap().to_vec(),
+            vec![true, false, true, true]
+        );
+        assert_eq!(
+            a.logical_xor(&b).unwrap().to_vec(),
+            vec![false, false, false, true]
+        );
+        assert_eq!(a.logical_not().to_vec(), vec![false, true, false, false]);
+    }
+
+    #[test]
+    fn logical_operators() {
+        let valid = Tensor::from(vec![true, true, false]);
+        let padded = Tensor::from(vec![false, true, false]);
+
+        assert_eq!(
+            (valid.clone() & !padded.clone()).to_vec(),
+            vec![true, false, false]
+        );
+        assert_eq!(
+            (valid.clone() | padded.clone()).to_vec(),
+            vec![true, true, false]
+        );
+        assert_eq!((valid ^ padded).to_vec(), vec![true, false, false]);
+    }
+}
@@ -0,0 +1,519 @@
+use crate::error::{Result, TensorError};
+use crate::types::Tensor;
+use crate::view::{broadcast_shapes, check_dim};
+use num_traits::{One, Zero};
+use std::ops::Mul;
+
+/// Side length of the square tiles the GEMM kernel walks, chosen so that a tile of each
+/// operand stays in L1 cache for common element sizes.
+const BLOCK: usize = 64;
+
+/// Accumulates `alpha` times the product of the row-major `m x k` matrix `a` and `k x n`
+/// matrix `b` into the row-major `m x n` matrix `out`.
+///
+/// With the `blas` feature, `f32` and `f64` products go to the CBLAS `gemm`; everything else
+/// uses [`tiled_gemm`].
+pub(crate) fn gemm<T: Zero + Mul<Output = T> + Copy + 'static>(
+    m: usize,
+    k: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    b: &[T],
+    out: &mut [T],
+) {
+    #[cfg(feature = "blas")]
+    if super::blas::try_gemm(m, k, n, alpha, a, b, out) {
+        return;
+    }
+    tiled_gemm(m, k, n, alpha, a, b, out);
+}
+
+/// The native [`gemm`]. The loops are tiled over all three dimensions and ordered `i-k-j`, so
+/// the innermost loop streams contiguously through a row of `b` and a row of `out`.
+pub(crate) fn tiled_gemm<T: Zero + Mul<Output = T> + Copy>(
+    m: usize,
+    k: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    b: &[T],
+    out: &mut [T],
+) {
+    for i0 in (0..m).step_by(BLOCK) {
+        for p0 in (0..k).step_by(BLOCK) {
+            for j0 in (0..n).step_by(BLOCK) {
+                let j1 = (j0 + BLOCK).min(n);
+                for i in i0..(i0 + BLOCK).min(m) {
+                    let out_row = &mut out[i * n + j0..i * n + j1];
+                    for p in p0..(p0 + BLOCK).min(k) {
+                        let scale = alpha * a[i * k + p];
+                        let b_row = &b[p * n + j0..p * n + j1];
+                        for (o, &x) in out_row.iter_mut().zip(b_row) {
+                            *o = *o + scale * x;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Runs [`gemm`] over consecutive `m x k`, `k x n` and `m x n` matrices of `a`, `b` and
+/// `out`.
+fn batched_gemm<T: Zero + Mul<Output = T> + Copy + 'static>(
+    m: usize,
+    k: usize,
+    n: usize,
+    alpha: T,
+    a: &[T],
+    b: &[T],
+    out: &mut [T],
+) {
+    if m * n == 0 {
+        return;
+    }
+    for (i, out) in out.chunks_mut(m * n).enumerate() {
+        gemm(
+            m,
+            k,
+            n,
+            alpha,
+            &a[i * m * k..(i + 1) * m * k],
+            &b[i * k * n..(i + 1) * k * n],
+            out,
+        );
+    }
+}
+
+fn check_vectors<T>(left: &Tensor<T>, right: &Tensor<T>) -> Result<()> {
+    for t in [left, right] {
+        if t.ndim() != 1 {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: t.ndim(),
+            });
+        }
+    }
+    Ok(())
+}
+
+impl<T: Zero + One + Mul<Output = T> + Copy + 'static> Tensor<T> {
+    /// Matrix product with NumPy `matmul` semantics.
+    ///
+    /// Two 2-D tensors multiply as `[m, k] x [k, n] -> [m, n]`. Higher-rank operands are
+    /// stacks of matrices in their last two dimensions, and the leading batch dimensions
+    /// broadcast against each other, so `[b, m, k] x [k, n]` gives `[b, m, n]`. A 1-D left
+    /// operand is treated as a row vector and a 1-D right operand as a column vector, with
+    /// the added dimension removed from the result.
+    ///
+    /// Fails with [`TensorError::RankMismatch`] if either operand is zero-dimensional, with
+    /// [`TensorError::ShapeMismatch`] if the inner dimensions differ, and with
+    /// [`TensorError::BroadcastMismatch`] if the batch dimensions do not broadcast. The
+    /// result requires grad if either operand does.
+    pub fn try_matmul(&self, rhs: &Tensor<T>) -> Result<Tensor<T>> {
+        for t in [self, rhs] {
+            if t.ndim() == 0 {
+                return Err(TensorError::RankMismatch {
+                    expected: 1,
+                    actual: 0,
+                });
+            }
+        }
+        let mut left_shape = self.shape().to_vec();
+        let mut right_shape = rhs.shape().to_vec();
+        if self.ndim() == 1 {
+            left_shape.insert(0, 1);
+        }
+        if rhs.ndim() == 1 {
+            right_shape.push(1);
+        }
+
+        let (m, k) = (
+            left_shape[left_shape.len() - 2],
+            left_shape[left_shape.len() - 1],
+        );
+        let n = right_shape[right_shape.len() - 1];
+        if right_shape[right_shape.len() - 2] != k {
+            return Err(TensorError::ShapeMismatch {
+                left: self.shape().to_vec(),
+                right: rhs.shape().to_vec(),
+            });
+        }
+        let batch = broadcast_shapes(
+            &left_shape[..left_shape.len() - 2],
+            &right_shape[..right_shape.len() - 2],
+        )?;
+
+        let expand = |t: &Tensor<T>, shape: &[usize], rows: usize, cols: usize| {
+            let full = batch
+                .iter()
+                .chain(&[rows, cols])
+                .copied()
+                .collect::<Vec<_>>();
+            let shape = shape.iter().map(|&size| size as isize).collect::<Vec<_>>();
+            Ok::<_, TensorError>(t.reshape(&shape)?.broadcast_to(&full)?.to_vec())
+        };
+        let left = expand(self, &left_shape, m, k)?;
+        let right = expand(rhs, &right_shape, k, n)?;
+
+        let mut out = vec![T::zero(); batch.iter().product::<usize>() * m * n];
+        batched_gemm(m, k, n, T::one(), &left, &right, &mut out);
+
+        let mut shape = batch;
+        if self.ndim() > 1 {
+            shape.push(m);
+        }
+        if rhs.ndim() > 1 {
+            shape.push(n);
+        }
+        let mut result = Tensor::from_vec(out, &shape);
+        result.requires_grad = self.requires_grad || rhs.requires_grad;
+        Ok(result)
+    }
+
+    /// Inner product of two 1-D tensors of equal length, as a zero-dimensional tensor.
+    pub fn dot(&self, rhs: &Tensor<T>) -> Result<Tensor<T>> {
+        check_vectors(self, rhs)?;
+        if self.numel() != rhs.numel() {
+            return Err(TensorError::ShapeMismatch {
+                left: self.shape().to_vec(),
+                right: rhs.shape().to_vec(),
+            });
+        }
+        self.try_matmul(rhs)
+    }
+
+    /// Outer product of two 1-D tensors, `[n] x [m] -> [n, m]`.
+    pub fn outer(&self, rhs: &Tensor<T>) -> Result<Tensor<T>> {
+        check_vectors(self, rhs)?;
+        let mut result = self
+            .reshape(&[-1, 1])?
+            .zip_map(rhs, |left, right| left * right)?;
+        result.requires_grad = self.requires_grad || rhs.requires_grad;
+        Ok(result)
+    }
+
+    /// Product of a 2-D matrix and a 1-D vector, `[m, k] x [k] -> [m]`.
+    pub fn mv(&self, vector: &Tensor<T>) -> Result<Tensor<T>> {
+        if self.ndim() != 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: self.ndim(),
+            });
+        }
+        if vector.ndim() != 1 {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: vector.ndim(),
+            });
+        }
+        self.try_matmul(vector)
+    }
+
+    /// Contracts dimensions `dims.0` of `self` with dimensions `dims.1` of `rhs` pairwise.
+    /// The result has the remaining dimensions of `self` followed by those of `rhs`.
+    pub fn tensordot(&self, rhs: &Tensor<T>, dims: (&[usize], &[usize])) -> Result<Tensor<T>> {
+        let (left_dims, right_dims) = dims;
+        if left_dims.len() != right_dims.len() {
+            return Err(TensorError::LengthMismatch {
+                expected: left_dims.len(),
+                actual: right_dims.len(),
+            });
+        }
+        for (&l, &r) in left_dims.iter().zip(right_dims) {
+            check_dim(l, self.ndim())?;
+            check_dim(r, rhs.ndim())?;
+            if self.shape()[l] != rhs.shape()[r] {
+                return Err(TensorError::ShapeMismatch {
+                    left: self.shape().to_vec(),
+                    right: rhs.shape().to_vec(),
+                });
+            }
+        }
+
+        let free = |t: &Tensor<T>, contracted: &[usize]| {
+            (0..t.ndim())
+                .filter(|d| !contracted.contains(d))
+                .collect::<Vec<_>>()
+        };
+        let (left_free, right_free) = (free(self, left_dims), free(rhs, right_dims));
+        let size = |t: &Tensor<T>, dims: &[usize]| {
+            dims.iter().map(|&d| t.shape()[d]).product::<usize>() as isize
+        };
+        let k = size(self, left_dims);
+
+        // A repeated dimension makes the order an invalid permutation, which `permute` reports.
+        let left = self
+            .permute(&[left_free.as_slice(), left_dims].concat())?
+            .reshape(&[size(self, &left_free), k])?;
+        let right = rhs
+            .permute(&[right_dims, right_free.as_slice()].concat())?
+            .reshape(&[k, size(rhs, &right_free)])?;
+
+        let shape = left_free
+            .iter()
+            .map(|&d| self.shape()[d])
+            .chain(right_free.iter().map(|&d| rhs.shape()[d]))
+            .map(|size| size as isize)
+            .collect::<Vec<_>>();
+        let mut result = left.try_matmul(&right)?.reshape(&shape)?;
+        result.requires_grad = self.requires_grad || rhs.requires_grad;
+        Ok(result)
+    }
+
+    /// Kronecker product: every element of `self` scales a full copy of `rhs`. The operands
+    /// are aligned by prepending size-1 dimensions to the one of lower rank, and each output
+    /// dimension has the product of the two sizes.
+    pub fn kron(&self, rhs: &Tensor<T>) -> Result<Tensor<T>> {
+        let ndim = self.ndim().max(rhs.ndim());
+        let padded = |t: &Tensor<T>| {
+            let mut shape = vec![1; ndim - t.ndim()];
+            shape.extend_from_slice(t.shape());
+            shape
+        };
+        let (left_shape, right_shape) = (padded(self), padded(rhs));
+
+        // Interleave the dimensions so broadcasting forms `[a0, b0, a1, b1, ...]`.
+        let interleave = |shape: &[usize], left: bool| {
+            shape
+                .iter()
+                .flat_map(|&size| if left { [size, 1] } else { [1, size] })
+                .map(|size| size as isize)
+                .collect::<Vec<_>>()
+        };
+        let product = self
+            .reshape(&interleave(&left_shape, true))?
+            .zip_map(&rhs.reshape(&interleave(&right_shape, false))?, |a, b| {
+                a * b
+            })?;
+
+        let shape = left_shape
+            .iter()
+            .zip(&right_shape)
+            .map(|(&a, &b)| (a * b) as isize)
+            .collect::<Vec<_>>();
+        let mut result = product.reshape(&shape)?;
+        result.requires_grad = self.requires_grad || rhs.requires_grad;
+        Ok(result)
+    }
+
+    /// Fused `beta * self + alpha * (a x b)` for 2-D `a` and `b`, where `self` broadcasts to
+    /// the `[m, n]` result. The product accumulates straight into the scaled bias, so no
+    /// separate product tensor is allocated. With `beta` zero, `self` is not read at all and
+    /// a `NaN` in it does not propagate.
+    pub fn addmm(&self, a: &Tensor<T>, b: &Tensor<T>, alpha: T, beta: T) -> Result<Tensor<T>> {
+        self.add_product(a, b, alpha, beta, 2)
+    }
+
+    /// Batched [`Tensor::addmm`]: `a` is `[batch, m, k]`, `b` is `[batch, k, n]` and `self`
+    /// broadcasts to `[batch, m, n]`.
+    pub fn baddbmm(&self, a: &Tensor<T>, b: &Tensor<T>, alpha: T, beta: T) -> Result<Tensor<T>> {
+        self.add_product(a, b, alpha, beta, 3)
+    }
+
+    fn add_product(
+        &self,
+        a: &Tensor<T>,
+        b: &Tensor<T>,
+        alpha: T,
+        beta: T,
+        rank: usize,
+    ) -> Result<Tensor<T>> {
+        for t in [a, b] {
+            if t.ndim() != rank {
+                return Err(TensorError::RankMismatch {
+                    expected: rank,
+                    actual: t.ndim(),
+                });
+            }
+        }
+        let (a_shape, b_shape) = (a.shape(), b.shape());
+        let (m, k, n) = (a_shape[rank - 2], a_shape[rank - 1], b_shape[rank - 1]);
+        if b_shape[rank - 2] != k || a_shape[..rank - 2] != b_shape[..rank - 2] {
+            return Err(TensorError::ShapeMismatch {
+                left: a_shape.to_vec(),
+                right: b_shape.to_vec(),
+            });
+        }
+
+        let mut shape = a_shape[..rank - 2].to_vec();
+        shape.extend([m, n]);
+        let bias = self.broadcast_to(&shape)?;
+        let mut out = if beta.is_zero() {
+            vec![T::zero(); bias.numel()]
+        } else {
+            bias.to_vec().into_iter().map(|x| beta * x).collect()
+        };
+        batched_gemm(m, k, n, alpha, &a.to_vec(), &b.to_vec(), &mut out);
+
+        let mut result = Tensor::from_vec(out, &shape);
+        result.requires_grad = self.requires_grad || a.requires_grad || b.requires_grad;
+        Ok(result)
+    }
+
+    /// Matrix product; see [`Tensor::try_matmul`].
+    ///
+    /// Panics if the shapes are incompatible.
+    pub fn matmul(&self, rhs: &Tensor<T>) -> Tensor<T> {
+        self.try_matmul(rhs)
+            .unwrap_or_else(|e| panic!("Tensor matmul failed: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    #[test]
+    fn matmul_2d() {
+        let a = crate::tensor![[1, 2, 3], [4, 5, 6]];
+        let b = crate::tensor![[7, 8], [9, 10], [11, 12]];
+
+        let product = a.matmul(&b);
+
+        assert_eq!(product.shape(), &[2, 2]);
+        assert_eq!(product.to_vec(), vec![58, 64, 139, 154]);
+        assert_eq!(
+            a.matmul(&a.transpose(0, 1).unwrap()).to_vec(),
+            vec![14, 32, 32, 77]
+        );
+        assert_eq!(
+            a.try_matmul(&a).err(),
+            Some(TensorError::ShapeMismatch {
+                left: vec![2, 3],
+                right: vec![2, 3],
+            })
+        );
+        assert!(a.try_matmul(&Tensor::scalar(2)).is_err());
+    }
+
+    #[test]
+    fn matmul_crosses_block_boundaries() {
+        let (m, k, n) = (70, 130, 65);
+        let a = Tensor::arange(0i64, (m * k) as i64, 1).reshape(&[m as isize, k as isize]);
+        let b = Tensor::arange(0i64, (k * n) as i64, 1).reshape(&[k as isize, n as isize]);
+        let (a, b) = (a.unwrap(), b.unwrap());
+
+        let product = a.matmul(&b).to_vec();
+        let (a, b) = (a.to_vec(), b.to_vec());
+
+        for (i, j) in [(0, 0), (63, 64), (64, 63), (69, 64)] {
+            let expected = (0..k).map(|p| a[i * k + p] * b[p * n + j]).sum::<i64>();
+            assert_eq!(product[i * n + j], expected);
+        }
+    }
+
+    #[test]
+    fn batched_matmul_broadcasts() {
+        let a = Tensor::arange(0, 12, 1).reshape(&[2, 1, 2, 3]).unwrap();
+        let b = Tensor::arange(0, 18, 1).reshape(&[3, 3, 2]).unwrap();
+        let v = Tensor::from(vec![1, 0, -1]);
+
+        let product = a.matmul(&b);
+
+        assert_eq!(product.shape(), &[2, 3, 2, 2]);
+        assert_eq!(product.to_vec()[..4], [10, 13, 28, 40]);
+        assert_eq!(product.to_vec()[20..], [298, 319, 424, 454]);
+        assert_eq!(a.matmul(&v).shape(), &[2, 1, 2]);
+        assert_eq!(a.matmul(&v).to_vec(), vec![-2, -2, -2, -2]);
+        assert_eq!(v.matmul(&b).shape(), &[3, 2]);
+        assert_eq!(v.matmul(&v).shape(), &[] as &[usize]);
+        assert_eq!(v.matmul(&v).to_vec(), vec![2]);
+        assert!(a.try_matmul(&Tensor::<i32>::zeros(&[3, 1, 3, 2])).is_err());
+    }
+
+    #[test]
+    fn dot_outer_and_mv() {
+        let u = Tensor::from(vec![1, 2, 3]);
+        let v = Tensor::from(vec![4, 5]);
+        let m = crate::tensor![[1, 0, 2], [0, 1, 0]];
+
+        assert_eq!(u.dot(&u).unwrap().ndim(), 0);
+        assert_eq!(u.dot(&u).unwrap().to_vec(), vec![14]);
+        assert!(u.dot(&v).is_err());
+        assert_eq!(u.outer(&v).unwrap().shape(), &[3, 2]);
+        assert_eq!(u.outer(&v).unwrap().to_vec(), vec![4, 5, 8, 10, 12, 15]);
+        assert_eq!(m.mv(&u).unwrap().to_vec(), vec![7, 2]);
+        assert!(m.mv(&m).is_err());
+        assert!(u.outer(&m).is_err());
+    }
+
+    #[test]
+    fn tensordot() {
+        let mut a = Tensor::arange(0, 24, 1).reshape(&[2, 3, 4]).unwrap();
+        let b = Tensor::arange(0, 12, 1).reshape(&[4, 3]).unwrap();
+        a.requires_grad = true;
+
+        let dot = a.tensordot(&b, (&[1, 2], &[1, 0])).unwrap();
+        let outer = a.tensordot(&b, (&[], &[])).unwrap();
+
+        assert_eq!(dot.shape(), &[2]);
+        assert!(dot.requires_grad && outer.requires_grad);
+        assert_eq!(
+            dot.to_vec(),
+            crate::ops::einsum::einsum("ijk,kj->i", &[&a, &b])
+                .unwrap()
+                .to_vec()
+        );
+        assert_eq!(outer.shape(), &[2, 3, 4, 4, 3]);
+        assert_eq!(
+            a.tensordot(&b, (&[2], &[0])).unwrap().to_vec(),
+            a.matmul(&b).to_vec()
+        );
+        assert!(a.tensordot(&b, (&[1], &[0])).is_err());
+        assert!(a.tensordot(&b, (&[2, 2], &[0, 0])).is_err());
+    }
+
+    #[test]
+    fn kron() {
+        let a = crate::tensor![[1, 2], [3, 4]];
+        let mut b = crate::tensor![[0, 1], [1, 0]];
+        b.requires_grad = true;
+
+        let product = a.kron(&b).unwrap();
+
+        assert_eq!(product.shape(), &[4, 4]);
+        assert!(product.requires_grad);
+        assert_eq!(
+            product.to_vec(),
+            vec![0, 1, 0, 2, 1, 0, 2, 0, 0, 3, 0, 4, 3, 0, 4, 0]
+        );
+        assert_eq!(
+            Tensor::from(vec![1, 10]).kron(&a).unwrap().to_vec(),
+            vec![1, 2, 10, 20, 3, 4, 30, 40]
+        );
+    }
+
+    #[test]
+    fn addmm_and_baddbmm() {
+        let a = crate::tensor![[1.0, 2.0], [3.0, 4.0]];
+        let b = crate::tensor![[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]];
+        let bias = Tensor::from(vec![1.0, 2.0, 3.0]);
+
+        let fused = bias.addmm(&a, &b, 2.0, 0.5).unwrap();
+        let batched = Tensor::scalar(1.0)
+            .baddbmm(
+                &a.reshape(&[1, 2, 2]).unwrap(),
+                &b.reshape(&[1, 2, 3]).unwrap(),
+                1.0,
+                1.0,
+            )
+            .unwrap();
+
+        assert_eq!(fused.shape(), &[2, 3]);
+        assert_eq!(fused.to_vec(), vec![2.5, 5.0, 7.5, 6.5, 9.0, 15.5]);
+        assert_eq!(batched.shape(), &[1, 2, 3]);
+        assert_eq!(batched.to_vec(), vec![2.0, 3.0, 4.0, 4.0, 5.0, 8.0]);
+        assert_eq!(
+            Tensor::scalar(f64::NAN)
+                .addmm(&a, &b, 1.0, 0.0)
+                .unwrap()
+                .to_vec(),
+            a.matmul(&b).to_vec()
+        );
+        assert!(bias.addmm(&b, &a, 1.0, 1.0).is_err());
+        assert!(bias.baddbmm(&a, &b, 1.0, 1.0).is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::error::Result;
+use crate::types::Tensor;
+use crate::view::{broadcast_shapes, check_dim};
+
+pub mod activation;
+mod arith;
+#[cfg(feature = "blas")]
+mod blas;
+pub(crate) mod clamp;
+mod compare;
+pub mod distance;
+pub mod einsum;
+pub mod embedding;
+mod fused;
+mod logical;
+pub(crate) mod matmul;
+pub(crate) mod special;
+mod trig;
+pub mod unary;
+
+impl<T: Clone> Tensor<T> {
+    /// Broadcasts `self` and `rhs` to a common shape and combines them elementwise with `f`
+    /// into a new contiguous tensor. Strides and offsets of both operands are respected.
+    ///
+    /// This is the building block of the binary ops, and an escape hatch for custom ones.
+    pub fn zip_map<U: Clone, V>(
+        &self,
+        rhs: &Tensor<U>,
+        mut f: impl FnMut(T, U) -> V,
+    ) -> Result<Tensor<V>> {
+        let shape = broadcast_shapes(self.shape(), rhs.shape())?;
+        let left = self.broadcast_to(&shape)?;
+        let right = rhs.broadcast_to(&shape)?;
+
+        let result_data = {
+            let left_data = left.base.data.borrow();
+            let right_data = right.base.data.borrow();
+            left.base
+                .storage_indices()
+                .zip(right.base.storage_indices())
+                .map(|(l, r)| f(left_data[l].clone(), right_data[r].clone()))
+                .collect()
+        };
+        Ok(Tensor::from_vec(result_data, &shape))
+    }
+
+    /// Like [`Tensor::zip_map`], but for three operands.
+    pub(crate) fn broadcast_zip3<U: Clone, W: Clone, V>(
+        &self,
+        second: &Tensor<U>,
+        third: &Tensor<W>,
+        mut f: impl FnMut(T, U, W) -> V,
+    ) -> Result<Tensor<V>> {
+        let shape = broadcast_shapes(
+            &broadcast_shapes(self.shape(), second.shape())?,
+            third.shape(),
+        )?;
+        let first = self.broadcast_to(&shape)?;
+        let second = second.broadcast_to(&shape)?;
+        let third = third.broadcast_to(&shape)?;
+
+        let result_data = {
+            let first_data = first.base.data.borrow();
+            let second_data = second.base.data.borrow();
+            let third_data = third.base.data.borrow();
+            first
+                .base
+                .storage_indices()
+                .zip(second.base.storage_indices())
+                .zip(third.base.storage_indices())
+                .map(|((a, b), c)| {
+                    f(
+                        first_data[a].clone(),
+                        second_data[b].clone(),
+                        third_data[c].clone(),
+                    )
+                })
+                .collect()
+        };
+        Ok(Tensor::from_vec(result_data, &shape))
+    }
+
+    /// Applies `f` to every element, in logical row-major order, into a new contiguous
+    /// tensor of the same shape. Strides and offset are respected and `requires_grad` is
+    /// kept.
+    pub fn map<U>(&self, mut f: impl FnMut(T) -> U) -> Tensor<U> {
+        let data = self.base.data.borrow();
+        let values = self
+            .base
+            .storage_indices()
+            .map(|i| f(data[i].clone()))
+            .collect();
+        let mut result = Tensor::from_vec(values, self.shape());
+        result.requires_grad = self.requires_grad;
+        result
+    }
+
+    /// Rewrites every 1-D lane along `dim` with `f`, which receives the lane's elements in
+    /// order, into a new contiguous tensor of the same shape.
+    pub(crate) fn map_lanes(&self, dim: usize, mut f: impl FnMut(&mut [T])) -> Result<Tensor<T>> {
+        check_dim(dim, self.ndim())?;
+        let last = self.ndim() - 1;
+        let moved = self.transpose(dim, last)?;
+
+        let mut values = moved.to_vec();
+        let len = self.shape()[dim];
+        if len > 0 {
+            values.chunks_mut(len).for_each(&mut f);
+        }
+        let mut result = Tensor::from_vec(values, moved.shape())
+            .transpose(dim, last)?
+            .contiguous();
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::Tensor;
+
+    #[test]
+    fn map_respects_strides() {
+        let t = crate::tensor![[1, 2], [3, 4]].transpose(0, 1).unwrap();
+
+        let labels = t.map(|x| format!("#{}", x));
+
+        assert_eq!(labels.shape(), &[2, 2]);
+        assert_eq!(labels.to_vec(), vec!["#1", "#3", "#2", "#4"]);
+    }
+
+    #[test]
+    fn zip_map_broadcasts_mixed_types() {
+        let counts = Tensor::from(vec![1usize, 2]);
+        let words = crate::tensor![["a".to_string()], ["b".to_string()]];
+
+        let repeated = words.zip_map(&counts, |word, n| word.repeat(n)).unwrap();
+
+        assert_eq!(repeated.to_vec(), vec!["a", "aa", "b", "bb"]);
+        assert!(
+            counts
+                .zip_map(&Tensor::from(vec![1, 2, 3]), |a, b| a + b)
+                .is_err()
+        );
+    }
+}
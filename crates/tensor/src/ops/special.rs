@@ -0,0 +1,233 @@
+use crate::types::Tensor;
+use num_traits::Float;
+use std::f64::consts::PI;
+
+/// Below this magnitude `erf` is summed as a series; above it `erfc` comes from a
+/// continued fraction, which converges quickly there and keeps full relative precision in
+/// the tail.
+const ERF_SERIES_LIMIT: f64 = 2.0;
+
+/// Lanczos coefficients for `g = 7`, accurate to about 15 significant digits.
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// `erf(x)` for `|x| < ERF_SERIES_LIMIT`, from the everywhere-positive series
+/// `2/sqrt(pi) * exp(-x^2) * sum(2^n x^(2n+1) / (1 * 3 * ... * (2n+1)))`.
+fn erf_series(x: f64) -> f64 {
+    let mut term = x;
+    let mut sum = x;
+    let mut n = 0.0;
+    while term.abs() > sum.abs() * f64::EPSILON {
+        n += 1.0;
+        term *= 2.0 * x * x / (2.0 * n + 1.0);
+        sum += term;
+    }
+    2.0 / PI.sqrt() * (-x * x).exp() * sum
+}
+
+/// `erfc(x)` for `x >= ERF_SERIES_LIMIT`, from the continued fraction
+/// `exp(-x^2)/sqrt(pi) / (x + (1/2)/(x + 1/(x + (3/2)/(x + ...))))`, evaluated with
+/// the modified Lentz method.
+fn erfc_continued_fraction(x: f64) -> f64 {
+    let tiny = 1e-300;
+    let mut f = x;
+    let mut c = x;
+    let mut d = 0.0;
+    for k in 1..500 {
+        let a = k as f64 / 2.0;
+        d = x + a * d;
+        d = if d == 0.0 { tiny } else { d.recip() };
+        c = x + a / c;
+        if c == 0.0 {
+            c = tiny;
+        }
+        let delta = c * d;
+        f *= delta;
+        if (delta - 1.0).abs() < f64::EPSILON {
+            break;
+        }
+    }
+    (-x * x).exp() / PI.sqrt() / f
+}
+
+pub(crate) fn erf(x: f64) -> f64 {
+    if x.is_nan() {
+        x
+    } else if x.is_infinite() {
+        1.0f64.copysign(x)
+    } else if x.abs() < ERF_SERIES_LIMIT {
+        erf_series(x)
+    } else {
+        (1.0 - erfc_continued_fraction(x.abs())).copysign(x)
+    }
+}
+
+pub(crate) fn erfc(x: f64) -> f64 {
+    if x.is_nan() {
+        x
+    } else if x.is_infinite() {
+        1.0 - 1.0f64.copysign(x)
+    } else if x >= ERF_SERIES_LIMIT {
+        erfc_continued_fraction(x)
+    } else if x <= -ERF_SERIES_LIMIT {
+        2.0 - erfc_continued_fraction(-x)
+    } else {
+        1.0 - erf_series(x)
+    }
+}
+
+pub(crate) fn lgamma(x: f64) -> f64 {
+    if x.is_infinite() {
+        return f64::INFINITY;
+    }
+    if x < 0.5 {
+        // Reflection formula; poles at the non-positive integers come out as +inf.
+        return (PI / (PI * x).sin().abs()).ln() - lgamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let sum = LANCZOS_COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS_COEFFICIENTS[0], |acc, (i, &c)| {
+            acc + c / (x + i as f64 + 1.0)
+        });
+    let t = x + LANCZOS_G + 0.5;
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+pub(crate) fn digamma(x: f64) -> f64 {
+    if x <= 0.0 && x == x.floor() {
+        return f64::NAN;
+    }
+    if x < 0.5 {
+        return digamma(1.0 - x) - PI / (PI * x).tan();
+    }
+    // Shift up with psi(x) = psi(x + 1) - 1/x until the asymptotic series is accurate.
+    let mut x = x;
+    let mut result = 0.0;
+    while x < 10.0 {
+        result -= x.recip();
+        x += 1.0;
+    }
+    let inv2 = (x * x).recip();
+    let series = inv2
+        * (1.0 / 12.0
+            - inv2 * (1.0 / 120.0 - inv2 * (1.0 / 252.0 - inv2 * (1.0 / 240.0 - inv2 / 132.0))));
+    result + x.ln() - 0.5 / x - series
+}
+
+/// Evaluates the `f64` kernel `f` at `x`.
+pub(crate) fn via_f64<T: Float>(x: T, f: fn(f64) -> f64) -> T {
+    T::from(f(x.to_f64().unwrap())).unwrap()
+}
+
+impl<T: Float> Tensor<T> {
+    /// Elementwise Gauss error function.
+    pub fn erf(&self) -> Tensor<T> {
+        self.map(|x| via_f64(x, erf))
+    }
+
+    /// Elementwise complementary error function `1 - erf(x)`, accurate in the tail where
+    /// the subtraction would cancel.
+    pub fn erfc(&self) -> Tensor<T> {
+        self.map(|x| via_f64(x, erfc))
+    }
+
+    /// Elementwise natural logarithm of the absolute value of the gamma function.
+    pub fn lgamma(&self) -> Tensor<T> {
+        self.map(|x| via_f64(x, lgamma))
+    }
+
+    /// Elementwise digamma function, the derivative of [`Tensor::lgamma`]. Returns `NaN`
+    /// at its poles, the non-positive integers.
+    pub fn digamma(&self) -> Tensor<T> {
+        self.map(|x| via_f64(x, digamma))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::Tensor;
+
+    fn assert_close(actual: Vec<f64>, expected: &[f64]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() <= 1e-13 * e.abs().max(1.0), "{} != {}", a, e);
+        }
+    }
+
+    #[test]
+    fn error_functions() {
+        let t = Tensor::from(vec![0.0, 1.0, -1.0, 3.0]);
+
+        assert_close(
+            t.erf().to_vec(),
+            &[
+                0.0,
+                0.842_700_792_949_714_9,
+                -0.842_700_792_949_714_9,
+                0.999_977_909_503_001_4,
+            ],
+        );
+        assert_close(
+            t.erfc().to_vec(),
+            &[
+                1.0,
+                0.157_299_207_050_285_13,
+                1.842_700_792_949_715,
+                2.209_049_699_858_544e-5,
+            ],
+        );
+        assert!(
+            (Tensor::from(vec![10.0f64]).erfc().to_vec()[0] / 2.088_487_583_762_545e-45 - 1.0)
+                .abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn gamma_functions() {
+        let t = Tensor::from(vec![1.0, 0.5, 10.0, -0.5]);
+
+        assert_close(
+            t.lgamma().to_vec(),
+            &[
+                0.0,
+                0.572_364_942_924_700_1,
+                12.801_827_480_081_469,
+                1.265_512_123_484_645_4,
+            ],
+        );
+        assert_close(
+            t.digamma().to_vec(),
+            &[
+                -0.577_215_664_901_532_9,
+                -1.963_510_026_021_423_5,
+                2.251_752_589_066_721,
+                0.036_489_973_978_576_52,
+            ],
+        );
+        assert!(Tensor::from(vec![0.0f32]).digamma().to_vec()[0].is_nan());
+    }
+
+    #[test]
+    fn infinite_inputs() {
+        let t = Tensor::from(vec![f64::INFINITY, f64::NEG_INFINITY]);
+
+        assert_eq!(t.erf().to_vec(), vec![1.0, -1.0]);
+        assert_eq!(t.erfc().to_vec(), vec![0.0, 2.0]);
+        assert_eq!(t.lgamma().to_vec(), vec![f64::INFINITY; 2]);
+        let digamma = t.digamma().to_vec();
+        assert_eq!(digamma[0], f64::INFINITY);
+        assert!(digamma[1].is_nan());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::error::Result;
+use crate::types::Tensor;
+use num_traits::Float;
+
+impl<T: Float> Tensor<T> {
+    /// Elementwise sine, in radians.
+    pub fn sin(&self) -> Tensor<T> {
+        self.map(T::sin)
+    }
+
+    /// Elementwise cosine, in radians.
+    pub fn cos(&self) -> Tensor<T> {
+        self.map(T::cos)
+    }
+
+    /// Elementwise tangent, in radians.
+    pub fn tan(&self) -> Tensor<T> {
+        self.map(T::tan)
+    }
+
+    /// Elementwise arcsine, in radians in `[-pi/2, pi/2]`.
+    pub fn asin(&self) -> Tensor<T> {
+        self.map(T::asin)
+    }
+
+    /// Elementwise arccosine, in radians in `[0, pi]`.
+    pub fn acos(&self) -> Tensor<T> {
+        self.map(T::acos)
+    }
+
+    /// Elementwise arctangent, in radians in `[-pi/2, pi/2]`.
+    pub fn atan(&self) -> Tensor<T> {
+        self.map(T::atan)
+    }
+
+    /// Four-quadrant arctangent of `self / other` (`self` is the y coordinate), after
+    /// broadcasting both to a common shape.
+    pub fn atan2(&self, other: &Tensor<T>) -> Result<Tensor<T>> {
+        let mut result = self.zip_map(other, T::atan2)?;
+        result.requires_grad = self.requires_grad || other.requires_grad;
+        Ok(result)
+    }
+
+    /// Elementwise hyperbolic sine.
+    pub fn sinh(&self) -> Tensor<T> {
+        self.map(T::sinh)
+    }
+
+    /// Elementwise hyperbolic cosine.
+    pub fn cosh(&self) -> Tensor<T> {
+        self.map(T::cosh)
+    }
+
+    /// Elementwise hyperbolic tangent.
+    pub fn tanh(&self) -> Tensor<T> {
+        self.map(T::tanh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::Tensor;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn trigonometric() {
+        let t = Tensor::from(vec![0.0, FRAC_PI_2]);
+
+        assert_eq!(t.sin().to_vec(), vec![0.0, 1.0]);
+        assert_eq!(t.cos().to_vec()[0], 1.0);
+        assert_eq!(Tensor::from(vec![1.0]).asin().to_vec(), vec![FRAC_PI_2]);
+        assert_eq!(Tensor::from(vec![-1.0]).acos().to_vec(), vec![PI]);
+        assert_eq!(Tensor::from(vec![1.0]).atan().to_vec(), vec![FRAC_PI_4]);
+    }
+
+    #[test]
+    fn atan2_broadcasts() {
+        let y = Tensor::from(vec![1.0, -1.0]);
+        let x = Tensor::scalar(1.0);
+
+        assert_eq!(y.atan2(&x).unwrap().to_vec(), vec![FRAC_PI_4, -FRAC_PI_4]);
+        assert!(y.atan2(&Tensor::from(vec![1.0, 2.0, 3.0])).is_err());
+    }
+
+    #[test]
+    fn hyperbolic() {
+        let t = Tensor::from(vec![0.0f32]);
+
+        assert_eq!(t.sinh().to_vec(), vec![0.0]);
+        assert_eq!(t.cosh().to_vec(), vec![1.0]);
+        assert_eq!(t.tanh().to_vec(), vec![0.0]);
+        assert_eq!(Tensor::from(vec![100.0f32]).tanh().to_vec(), vec![1.0]);
+    }
+}
@@ -0,0 +1,318 @@
+use crate::error::{Result, TensorError};
+use crate::types::Tensor;
+use num_traits::Float;
+use std::ops::Mul;
+
+impl<T: Float> Tensor<T> {
+    /// Elementwise `e^x`.
+    pub fn exp(&self) -> Tensor<T> {
+        self.map(T::exp)
+    }
+
+    /// Elementwise natural logarithm.
+    pub fn ln(&self) -> Tensor<T> {
+        self.map(T::ln)
+    }
+
+    /// Elementwise base-2 logarithm.
+    pub fn log2(&self) -> Tensor<T> {
+        self.map(T::log2)
+    }
+
+    /// Elementwise base-10 logarithm.
+    pub fn log10(&self) -> Tensor<T> {
+        self.map(T::log10)
+    }
+
+    /// Elementwise square root.
+    pub fn sqrt(&self) -> Tensor<T> {
+        self.map(T::sqrt)
+    }
+
+    /// Elementwise reciprocal square root, `1 / sqrt(x)`.
+    pub fn rsqrt(&self) -> Tensor<T> {
+        self.map(|x| x.sqrt().recip())
+    }
+
+    /// Elementwise reciprocal, `1 / x`.
+    pub fn recip(&self) -> Tensor<T> {
+        self.map(T::recip)
+    }
+
+    /// Elementwise `e^x - 1`, accurate for `x` near zero.
+    pub fn expm1(&self) -> Tensor<T> {
+        self.map(T::exp_m1)
+    }
+
+    /// Elementwise `ln(1 + x)`, accurate for `x` near zero.
+    pub fn log1p(&self) -> Tensor<T> {
+        self.map(T::ln_1p)
+    }
+
+    /// Elementwise logistic function `1 / (1 + e^-x)`. Only ever exponentiates a
+    /// non-positive number, so it cannot overflow for large `|x|`.
+    pub fn sigmoid(&self) -> Tensor<T> {
+        self.map(sigmoid)
+    }
+
+    /// Elementwise `ln(1 + e^x)`, computed as `max(x, 0) + ln(1 + e^-|x|)` so that it
+    /// neither overflows for large `x` nor loses precision for very negative `x`.
+    pub fn softplus(&self) -> Tensor<T> {
+        self.map(softplus)
+    }
+
+    /// Boolean mask of the `NaN` elements.
+    pub fn isnan(&self) -> Tensor<bool> {
+        self.mask_where(T::is_nan)
+    }
+
+    /// Boolean mask of the positive or negative infinite elements.
+    pub fn isinf(&self) -> Tensor<bool> {
+        self.mask_where(T::is_infinite)
+    }
+
+    /// Boolean mask of the elements that are neither infinite nor `NaN`.
+    pub fn isfinite(&self) -> Tensor<bool> {
+        self.mask_where(T::is_finite)
+    }
+
+    fn mask_where(&self, predicate: impl FnMut(T) -> bool) -> Tensor<bool> {
+        let mut mask = self.map(predicate);
+        mask.requires_grad = false;
+        mask
+    }
+
+    /// Replaces `NaN` with `nan`, and positive and negative infinity with `posinf` and
+    /// `neginf`, which default to the largest and lowest finite values of `T`.
+    pub fn nan_to_num(&self, nan: T, posinf: Option<T>, neginf: Option<T>) -> Tensor<T> {
+        let posinf = posinf.unwrap_or_else(T::max_value);
+        let neginf = neginf.unwrap_or_else(T::min_value);
+        self.map(|x| {
+            if x.is_nan() {
+                nan
+            } else if x == T::infinity() {
+                posinf
+            } else if x == T::neg_infinity() {
+                neginf
+            } else {
+                x
+            }
+        })
+    }
+
+    /// Elementwise largest integer less than or equal to `x`.
+    pub fn floor(&self) -> Tensor<T> {
+        self.map(T::floor)
+    }
+
+    /// Elementwise smallest integer greater than or equal to `x`.
+    pub fn ceil(&self) -> Tensor<T> {
+        self.map(T::ceil)
+    }
+
+    /// Elementwise nearest integer, rounding half-way cases away from zero.
+    pub fn round(&self) -> Tensor<T> {
+        self.map(T::round)
+    }
+
+    /// Elementwise integer part, rounding towards zero.
+    pub fn trunc(&self) -> Tensor<T> {
+        self.map(T::trunc)
+    }
+
+    /// Elementwise fractional part, `x - x.trunc()`, which keeps the sign of `x`.
+    pub fn frac(&self) -> Tensor<T> {
+        self.map(T::fract)
+    }
+
+    /// Raises each element to the matching element of `exponent`, after broadcasting both
+    /// to a common shape.
+    pub fn pow(&self, exponent: &Tensor<T>) -> Result<Tensor<T>> {
+        let mut result = self.zip_map(exponent, T::powf)?;
+        result.requires_grad = self.requires_grad || exponent.requires_grad;
+        Ok(result)
+    }
+
+    /// Raises each element to the float power `exponent`.
+    pub fn powf(&self, exponent: T) -> Tensor<T> {
+        self.map(|x| x.powf(exponent))
+    }
+
+    /// Raises each element to the integer power `exponent`, which is faster than
+    /// [`Tensor::powf`].
+    pub fn powi(&self, exponent: i32) -> Tensor<T> {
+        self.map(|x| x.powi(exponent))
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Tensor<T> {
+    /// Elementwise `x * x`.
+    pub fn square(&self) -> Tensor<T> {
+        self.map(|x| x * x)
+    }
+}
+
+pub(crate) fn sigmoid<T: Float>(x: T) -> T {
+    if x >= T::zero() {
+        (T::one() + (-x).exp()).recip()
+    } else {
+        let e = x.exp();
+        e / (T::one() + e)
+    }
+}
+
+pub(crate) fn softplus<T: Float>(x: T) -> T {
+    x.max(T::zero()) + (-x.abs()).exp().ln_1p()
+}
+
+/// Chain rule for an elementwise op: multiplies `grad_output` by `derivative(x)` for each
+/// element `x` of `input`. Both tensors must have the same shape.
+pub(crate) fn unary_backward<T: Float>(
+    input: &Tensor<T>,
+    grad_output: &Tensor<T>,
+    mut derivative: impl FnMut(T) -> T,
+) -> Result<Tensor<T>> {
+    if input.shape() != grad_output.shape() {
+        return Err(TensorError::ShapeMismatch {
+            left: input.shape().to_vec(),
+            right: grad_output.shape().to_vec(),
+        });
+    }
+    input.zip_map(grad_output, |x, grad| grad * derivative(x))
+}
+
+/// Gradient of [`Tensor::exp`] with respect to `input`.
+pub fn exp_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    unary_backward(input, grad_output, T::exp)
+}
+
+/// Gradient of [`Tensor::ln`] with respect to `input`.
+pub fn ln_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    unary_backward(input, grad_output, T::recip)
+}
+
+/// Gradient of [`Tensor::log2`] with respect to `input`.
+pub fn log2_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    let ln_2 = T::from(std::f64::consts::LN_2).unwrap();
+    unary_backward(input, grad_output, |x| (x * ln_2).recip())
+}
+
+/// Gradient of [`Tensor::log10`] with respect to `input`.
+pub fn log10_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    let ln_10 = T::from(std::f64::consts::LN_10).unwrap();
+    unary_backward(input, grad_output, |x| (x * ln_10).recip())
+}
+
+/// Gradient of [`Tensor::sqrt`] with respect to `input`.
+pub fn sqrt_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    let two = T::one() + T::one();
+    unary_backward(input, grad_output, |x| (two * x.sqrt()).recip())
+}
+
+/// Gradient of [`Tensor::rsqrt`] with respect to `input`.
+pub fn rsqrt_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    let two = T::one() + T::one();
+    unary_backward(input, grad_output, |x| -(two * x * x.sqrt()).recip())
+}
+
+/// Gradient of [`Tensor::recip`] with respect to `input`.
+pub fn recip_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    unary_backward(input, grad_output, |x| -(x * x).recip())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{exp_backward, ln_backward, rsqrt_backward, sqrt_backward};
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    #[test]
+    fn unary_math() {
+        let t = Tensor::from(vec![1.0f64, 4.0, 16.0]);
+
+        assert_eq!(t.sqrt().to_vec(), vec![1.0, 2.0, 4.0]);
+        assert_eq!(t.rsqrt().to_vec(), vec![1.0, 0.5, 0.25]);
+        assert_eq!(t.recip().to_vec(), vec![1.0, 0.25, 0.0625]);
+        assert_eq!(t.log2().to_vec(), vec![0.0, 2.0, 4.0]);
+        assert!(
+            t.ln()
+                .exp()
+                .iter()
+                .zip(t.iter())
+                .all(|(a, b)| (a - b).abs() < 1e-12)
+        );
+        assert_eq!(Tensor::from(vec![1000.0f32]).log10().to_vec(), vec![3.0]);
+    }
+
+    #[test]
+    fn stable_exponential_ops() {
+        let t = Tensor::from(vec![-1000.0, 0.0, 1000.0]);
+
+        assert_eq!(t.sigmoid().to_vec(), vec![0.0, 0.5, 1.0]);
+        assert_eq!(t.softplus().to_vec(), vec![0.0, 2.0f64.ln(), 1000.0]);
+        assert_eq!(Tensor::from(vec![1e-20]).expm1().to_vec(), vec![1e-20]);
+        assert_eq!(Tensor::from(vec![1e-20]).log1p().to_vec(), vec![1e-20]);
+    }
+
+    #[test]
+    fn non_finite_masks_and_nan_to_num() {
+        let t = Tensor::from(vec![1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+
+        assert_eq!(t.isnan().to_vec(), vec![false, true, false, false]);
+        assert_eq!(t.isinf().to_vec(), vec![false, false, true, true]);
+        assert_eq!(t.isfinite().to_vec(), vec![true, false, false, false]);
+        assert_eq!(
+            t.nan_to_num(0.0, None, Some(-1.0)).to_vec(),
+            vec![1.0, 0.0, f32::MAX, -1.0]
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        let t = Tensor::from(vec![-1.5, -0.25, 0.5, 2.75]);
+
+        assert_eq!(t.floor().to_vec(), vec![-2.0, -1.0, 0.0, 2.0]);
+        assert_eq!(t.ceil().to_vec(), vec![-1.0, -0.0, 1.0, 3.0]);
+        assert_eq!(t.round().to_vec(), vec![-2.0, -0.0, 1.0, 3.0]);
+        assert_eq!(t.trunc().to_vec(), vec![-1.0, -0.0, 0.0, 2.0]);
+        assert_eq!(t.frac().to_vec(), vec![-0.5, -0.25, 0.5, 0.75]);
+    }
+
+    #[test]
+    fn powers() {
+        let t = Tensor::from(vec![1.0, 2.0, 3.0]);
+        let exponents = crate::tensor![[2.0], [0.5]];
+
+        let pow = t.pow(&exponents).unwrap();
+
+        assert_eq!(pow.shape(), &[2, 3]);
+        assert_eq!(pow.to_vec()[..3], [1.0, 4.0, 9.0]);
+        assert_eq!(t.powi(3).to_vec(), vec![1.0, 8.0, 27.0]);
+        assert_eq!(t.powf(2.0).to_vec(), t.square().to_vec());
+        assert_eq!(Tensor::from(vec![-3, 4]).square().to_vec(), vec![9, 16]);
+    }
+
+    #[test]
+    fn unary_backward() {
+        let x = Tensor::from(vec![1.0, 4.0]);
+        let grad = Tensor::from(vec![2.0, 2.0]);
+
+        assert_eq!(
+            exp_backward(&x, &grad).unwrap().to_vec(),
+            x.exp().mul_scalar(2.0).to_vec()
+        );
+        assert_eq!(ln_backward(&x, &grad).unwrap().to_vec(), vec![2.0, 0.5]);
+        assert_eq!(sqrt_backward(&x, &grad).unwrap().to_vec(), vec![1.0, 0.5]);
+        assert_eq!(
+            rsqrt_backward(&x, &grad).unwrap().to_vec(),
+            vec![-1.0, -0.125]
+        );
+        assert_eq!(
+            exp_backward(&x, &Tensor::from(vec![1.0])).err(),
+            Some(TensorError::ShapeMismatch {
+                left: vec![2],
+                right: vec![1],
+            })
+        );
+    }
+}
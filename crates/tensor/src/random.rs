@@ -0,0 +1,133 @@
+use crate::shape::Shape;
+use crate::types::Tensor;
+use rand::SeedableRng;
+use rand::distr::uniform::SampleUniform;
+use rand::distr::{Distribution, StandardUniform, Uniform};
+use rand::rngs::StdRng;
+use rand_distr::StandardNormal;
+
+/// Seedable random number generator used by the random tensor constructors.
+///
+/// Two generators created with the same seed produce identical tensors, which keeps
+/// training runs and tests reproducible.
+#[derive(Clone, Debug)]
+pub struct TensorRng {
+    inner: StdRng,
+}
+
+impl TensorRng {
+    /// Creates a deterministic generator from `seed`.
+    pub fn seed_from_u64(seed: u64) -> Self {
+        TensorRng {
+            inner: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Creates a generator seeded from the operating system's entropy source.
+    pub fn from_os_rng() -> Self {
+        TensorRng {
+            inner: StdRng::from_os_rng(),
+        }
+    }
+
+    fn sample_vec<T, D: Distribution<T>>(&mut self, shape: &[usize], dist: D) -> Vec<T> {
+        let numel = Shape::from(shape)
+            .checked_numel()
+            .unwrap_or_else(|| panic!("Tensor shape {:?} overflows usize", shape));
+        (&dist).sample_iter(&mut self.inner).take(numel).collect()
+    }
+}
+
+impl<T> Tensor<T>
+where
+    StandardUniform: Distribution<T>,
+{
+    /// Creates a tensor with values drawn uniformly from `[0, 1)` for floats
+    /// (or the full range for integers).
+    pub fn rand(shape: &[usize], rng: &mut TensorRng) -> Self {
+        Tensor::from_vec(rng.sample_vec(shape, StandardUniform), shape)
+    }
+
+    /// Like [`Tensor::rand`], shaped like `other`.
+    pub fn rand_like(other: &Tensor<T>, rng: &mut TensorRng) -> Self {
+        Tensor::rand(other.shape(), rng)
+    }
+}
+
+impl<T> Tensor<T>
+where
+    StandardNormal: Distribution<T>,
+{
+    /// Creates a tensor with values drawn from the standard normal distribution.
+    pub fn randn(shape: &[usize], rng: &mut TensorRng) -> Self {
+        Tensor::from_vec(rng.sample_vec(shape, StandardNormal), shape)
+    }
+
+    /// Like [`Tensor::randn`], shaped like `other`.
+    pub fn randn_like(other: &Tensor<T>, rng: &mut TensorRng) -> Self {
+        Tensor::randn(other.shape(), rng)
+    }
+}
+
+impl<T: SampleUniform + PartialOrd> Tensor<T> {
+    /// Creates a tensor with values drawn uniformly from `[low, high)`.
+    ///
+    /// Panics if `low >= high`.
+    pub fn randint(low: T, high: T, shape: &[usize], rng: &mut TensorRng) -> Self {
+        let dist = Uniform::new(low, high)
+            .unwrap_or_else(|e| panic!("Tensor::randint requires low < high: {}", e));
+        Tensor::from_vec(rng.sample_vec(shape, dist), shape)
+    }
+
+    /// Like [`Tensor::randint`], shaped like `other`.
+    pub fn randint_like(other: &Tensor<T>, low: T, high: T, rng: &mut TensorRng) -> Self {
+        Tensor::randint(low, high, other.shape(), rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TensorRng;
+    use crate::types::Tensor;
+
+    #[test]
+    fn same_seed_is_reproducible() {
+        let a = Tensor::<f64>::randn(&[4, 4], &mut TensorRng::seed_from_u64(42));
+        let b = Tensor::<f64>::randn(&[4, 4], &mut TensorRng::seed_from_u64(42));
+
+        assert_eq!(a.shape(), &[4, 4]);
+        assert_eq!(*a.base.data.borrow(), *b.base.data.borrow());
+    }
+
+    #[test]
+    fn like_constructors() {
+        let template = Tensor::<f64>::zeros(&[2, 5]);
+        let mut rng = TensorRng::seed_from_u64(0);
+
+        assert_eq!(Tensor::rand_like(&template, &mut rng).shape(), &[2, 5]);
+        assert_eq!(Tensor::randn_like(&template, &mut rng).shape(), &[2, 5]);
+    }
+
+    #[test]
+    fn rand_and_randint_ranges() {
+        let mut rng = TensorRng::seed_from_u64(7);
+        let uniform = Tensor::<f32>::rand(&[100], &mut rng);
+        let ints = Tensor::randint(-3, 3, &[100], &mut rng);
+
+        assert!(
+            uniform
+                .base
+                .data
+                .borrow()
+                .iter()
+                .all(|&x| (0.0..1.0).contains(&x))
+        );
+        assert!(
+            ints.base
+                .data
+                .borrow()
+                .iter()
+                .all(|&x| (-3..3).contains(&x))
+        );
+    }
+}
@@ -0,0 +1,1160 @@
+use crate::error::{Result, TensorError};
+use crate::ops::clamp::clamp_value;
+use crate::types::Tensor;
+use crate::view::check_dim;
+use num_traits::{Float, One, Zero};
+use std::cmp::Ordering;
+use std::ops::{Add, Mul};
+
+impl<T: Clone> Tensor<T> {
+    /// Collapses the dimensions `dims` by passing each lane of their elements (in row-major
+    /// order over `dims`) to `f`. The reduced dimensions are dropped from the result, or
+    /// kept with size 1 when `keepdim` is set.
+    pub(crate) fn reduce_dims<U>(
+        &self,
+        dims: &[usize],
+        keepdim: bool,
+        mut f: impl FnMut(&[T]) -> U,
+    ) -> Result<Tensor<U>> {
+        for &dim in dims {
+            check_dim(dim, self.ndim())?;
+        }
+        let kept = (0..self.ndim())
+            .filter(|d| !dims.contains(d))
+            .collect::<Vec<_>>();
+        let order = kept.iter().chain(dims).copied().collect::<Vec<_>>();
+        let values = self.permute(&order)?.to_vec();
+
+        let lane_len = dims.iter().map(|&d| self.shape()[d]).product::<usize>();
+        let shape = (0..self.ndim())
+            .filter_map(|d| match (kept.contains(&d), keepdim) {
+                (true, _) => Some(self.shape()[d]),
+                (false, true) => Some(1),
+                (false, false) => None,
+            })
+            .collect::<Vec<_>>();
+        let lanes = kept.iter().map(|&d| self.shape()[d]).product::<usize>();
+
+        let reduced = if lane_len == 0 {
+            (0..lanes).map(|_| f(&[])).collect()
+        } else {
+            values.chunks(lane_len).map(f).collect()
+        };
+        Ok(Tensor::from_vec(reduced, &shape))
+    }
+}
+
+impl<T: Zero + Copy> Tensor<T> {
+    /// Sums every element into a zero-dimensional tensor. An empty tensor sums to zero.
+    pub fn sum(&self) -> Tensor<T> {
+        let total = self.to_vec().into_iter().fold(T::zero(), |acc, x| acc + x);
+        let mut result = Tensor::scalar(total);
+        result.requires_grad = self.requires_grad;
+        result
+    }
+
+    /// Sums over the dimensions `dims`, dropping them from the result or keeping them with
+    /// size 1 when `keepdim` is set. Summing over no dimensions returns a copy.
+    pub fn sum_dim(&self, dims: &[usize], keepdim: bool) -> Result<Tensor<T>> {
+        let mut result = self.reduce_dims(dims, keepdim, |lane| {
+            lane.iter().fold(T::zero(), |acc, &x| acc + x)
+        })?;
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+
+    /// Sum of the main diagonal of a 2-D tensor, as a zero-dimensional tensor.
+    pub fn trace(&self) -> Result<Tensor<T>> {
+        if self.ndim() != 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: self.ndim(),
+            });
+        }
+        let mut result = self.diagonal(0, 0, 1)?.sum();
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+}
+
+impl<T: One + Copy> Tensor<T> {
+    /// Multiplies every element into a zero-dimensional tensor. An empty tensor gives one.
+    pub fn prod(&self) -> Tensor<T> {
+        let total = self.to_vec().into_iter().fold(T::one(), |acc, x| acc * x);
+        let mut result = Tensor::scalar(total);
+        result.requires_grad = self.requires_grad;
+        result
+    }
+
+    /// Multiplies the elements along `dim`, dropping it from the result or keeping it with
+    /// size 1 when `keepdim` is set.
+    pub fn prod_dim(&self, dim: usize, keepdim: bool) -> Result<Tensor<T>> {
+        let mut result = self.reduce_dims(&[dim], keepdim, |lane| {
+            lane.iter().fold(T::one(), |acc, &x| acc * x)
+        })?;
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+}
+
+/// Replaces each element with the product of the other elements in its lane along `dim`.
+/// Built from prefix and suffix products rather than dividing the lane product, so lanes
+/// containing zeros get exact gradients.
+fn exclusive_products<T: One + Copy>(input: &Tensor<T>, dim: usize) -> Result<Tensor<T>> {
+    input.map_lanes(dim, |lane| {
+        let mut prefix = T::one();
+        let before = lane
+            .iter()
+            .map(|&x| {
+                let product = prefix;
+                prefix = prefix * x;
+                product
+            })
+            .collect::<Vec<_>>();
+        let mut suffix = T::one();
+        for i in (0..lane.len()).rev() {
+            let x = lane[i];
+            lane[i] = before[i] * suffix;
+            suffix = suffix * x;
+        }
+    })
+}
+
+/// Gradient of [`Tensor::prod`] with respect to `input`, given the zero-dimensional
+/// `grad_output`.
+pub fn prod_backward<T: Float>(input: &Tensor<T>, grad_output: &Tensor<T>) -> Result<Tensor<T>> {
+    if grad_output.ndim() != 0 {
+        return Err(TensorError::ShapeMismatch {
+            left: Vec::new(),
+            right: grad_output.shape().to_vec(),
+        });
+    }
+    let others = exclusive_products(&Tensor::from(input.to_vec()), 0)?;
+    Tensor::from_vec(others.to_vec(), input.shape()).try_mul(grad_output)
+}
+
+/// Gradient of [`Tensor::prod_dim`] with respect to `input`.
+pub fn prod_dim_backward<T: Float>(
+    input: &Tensor<T>,
+    grad_output: &Tensor<T>,
+    dim: usize,
+    keepdim: bool,
+) -> Result<Tensor<T>> {
+    check_dim(dim, input.ndim())?;
+    let mut kept = input.shape().to_vec();
+    kept[dim] = 1;
+    let mut expected = kept.clone();
+    if !keepdim {
+        expected.remove(dim);
+    }
+    if grad_output.shape() != expected {
+        return Err(TensorError::ShapeMismatch {
+            left: expected,
+            right: grad_output.shape().to_vec(),
+        });
+    }
+
+    let kept = kept.iter().map(|&size| size as isize).collect::<Vec<_>>();
+    exclusive_products(input, dim)?.try_mul(&grad_output.reshape(&kept)?)
+}
+
+impl<T: Clone + PartialEq + Default> Tensor<T> {
+    /// Whether any element differs from `T::default()` (zero for numbers, `false` for
+    /// `bool`). An empty tensor gives `false`.
+    pub fn any(&self) -> bool {
+        let zero = T::default();
+        self.iter().any(|x| x != zero)
+    }
+
+    /// Whether every element differs from `T::default()`. An empty tensor gives `true`.
+    pub fn all(&self) -> bool {
+        let zero = T::default();
+        self.iter().all(|x| x != zero)
+    }
+
+    /// [`Tensor::any`] over the lanes along `dim`.
+    pub fn any_dim(&self, dim: usize, keepdim: bool) -> Result<Tensor<bool>> {
+        let zero = T::default();
+        self.reduce_dims(&[dim], keepdim, |lane| lane.iter().any(|x| *x != zero))
+    }
+
+    /// [`Tensor::all`] over the lanes along `dim`.
+    pub fn all_dim(&self, dim: usize, keepdim: bool) -> Result<Tensor<bool>> {
+        let zero = T::default();
+        self.reduce_dims(&[dim], keepdim, |lane| lane.iter().all(|x| *x != zero))
+    }
+
+    /// Number of elements that differ from `T::default()`.
+    pub fn count_nonzero(&self) -> usize {
+        let zero = T::default();
+        self.iter().filter(|x| *x != zero).count()
+    }
+
+    /// [`Tensor::count_nonzero`] over the dimensions `dims`.
+    pub fn count_nonzero_dim(&self, dims: &[usize], keepdim: bool) -> Result<Tensor<i64>> {
+        let zero = T::default();
+        self.reduce_dims(dims, keepdim, |lane| {
+            lane.iter().filter(|x| **x != zero).count() as i64
+        })
+    }
+}
+
+impl Tensor<i64> {
+    /// Number of occurrences of each value `0..=max` in this 1-D tensor, padded with zeros to
+    /// at least `minlength` entries.
+    pub fn bincount(&self, minlength: usize) -> Result<Tensor<i64>> {
+        self.bincount_with(minlength, |_| 1)
+    }
+
+    /// Like [`Tensor::bincount`], but each occurrence adds its entry of `weights`, which
+    /// must have the same shape as this tensor.
+    pub fn bincount_weighted<W: Zero + Copy>(
+        &self,
+        weights: &Tensor<W>,
+        minlength: usize,
+    ) -> Result<Tensor<W>> {
+        if weights.shape() != self.shape() {
+            return Err(TensorError::ShapeMismatch {
+                left: self.shape().to_vec(),
+                right: weights.shape().to_vec(),
+            });
+        }
+        let weights = weights.to_vec();
+        self.bincount_with(minlength, |i| weights[i])
+    }
+
+    fn bincount_with<W: Zero + Copy>(
+        &self,
+        minlength: usize,
+        weight: impl Fn(usize) -> W,
+    ) -> Result<Tensor<W>> {
+        if self.ndim() != 1 {
+            return Err(TensorError::RankMismatch {
+                expected: 1,
+                actual: self.ndim(),
+            });
+        }
+        let values = self.to_vec();
+        if let Some(&value) = values.iter().find(|&&value| value < 0) {
+            return Err(TensorError::NegativeValue { value });
+        }
+
+        let len = values
+            .iter()
+            .map(|&value| value as usize + 1)
+            .max()
+            .unwrap_or(0)
+            .max(minlength);
+        let mut counts = vec![W::zero(); len];
+        for (i, &value) in values.iter().enumerate() {
+            counts[value as usize] = counts[value as usize] + weight(i);
+        }
+        Ok(Tensor::from(counts))
+    }
+}
+
+impl<T: Add<Output = T> + Copy> Tensor<T> {
+    /// Running sums along `dim`: element `i` of each lane is the sum of elements `0..=i`.
+    pub fn cumsum(&self, dim: usize) -> Result<Tensor<T>> {
+        self.map_lanes(dim, |lane| {
+            for i in 1..lane.len() {
+                lane[i] = lane[i - 1] + lane[i];
+            }
+        })
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Tensor<T> {
+    /// Running products along `dim`: element `i` of each lane is the product of elements
+    /// `0..=i`.
+    pub fn cumprod(&self, dim: usize) -> Result<Tensor<T>> {
+        self.map_lanes(dim, |lane| {
+            for i in 1..lane.len() {
+                lane[i] = lane[i - 1] * lane[i];
+            }
+        })
+    }
+}
+
+/// Orders partially ordered values with every `NaN` after all numbers.
+pub(crate) fn nan_last_cmp<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    let is_nan = |x: &T| x.partial_cmp(x).is_none();
+    a.partial_cmp(b)
+        .unwrap_or_else(|| is_nan(a).cmp(&is_nan(b)))
+}
+
+/// The distinct elements of a tensor, as returned by [`Tensor::unique`].
+pub struct Unique<T> {
+    /// The distinct elements in ascending order, with any `NaN`s last.
+    pub values: Tensor<T>,
+    /// For every input element, the position of its value in `values`. Shaped like the
+    /// input.
+    pub inverse: Tensor<i64>,
+    /// How many input elements equal each entry of `values`.
+    pub counts: Tensor<i64>,
+}
+
+impl<T: PartialOrd + Copy> Tensor<T> {
+    /// Running maxima along `dim`, with the index along `dim` where each was attained. Ties
+    /// take the latest index, and a `NaN` wins over every later element.
+    pub fn cummax(&self, dim: usize) -> Result<(Tensor<T>, Tensor<i64>)> {
+        self.cumulative_extreme(dim, |candidate, best| candidate >= best)
+    }
+
+    /// Running minima along `dim`, with the index along `dim` where each was attained. Ties
+    /// take the latest index, and a `NaN` wins over every later element.
+    pub fn cummin(&self, dim: usize) -> Result<(Tensor<T>, Tensor<i64>)> {
+        self.cumulative_extreme(dim, |candidate, best| candidate <= best)
+    }
+
+    /// Largest element along `dim` together with its index. The first occurrence wins a tie
+    /// and a `NaN` beats every number.
+    pub fn max_dim(&self, dim: usize, keepdim: bool) -> Result<(Tensor<T>, Tensor<i64>)> {
+        self.extreme_dim(dim, keepdim, Ordering::Greater)
+    }
+
+    /// Smallest element along `dim` together with its index; see [`Tensor::max_dim`].
+    pub fn min_dim(&self, dim: usize, keepdim: bool) -> Result<(Tensor<T>, Tensor<i64>)> {
+        self.extreme_dim(dim, keepdim, Ordering::Less)
+    }
+
+    /// Index of the largest element along `dim`; see [`Tensor::max_dim`].
+    pub fn argmax(&self, dim: usize, keepdim: bool) -> Result<Tensor<i64>> {
+        Ok(self.max_dim(dim, keepdim)?.1)
+    }
+
+    /// Index of the smallest element along `dim`; see [`Tensor::max_dim`].
+    pub fn argmin(&self, dim: usize, keepdim: bool) -> Result<Tensor<i64>> {
+        Ok(self.min_dim(dim, keepdim)?.1)
+    }
+
+    /// Sorted distinct elements of the flattened tensor, with the inverse mapping back to
+    /// the input and the number of occurrences of each. `NaN`s never compare equal, so each
+    /// one is kept separately.
+    pub fn unique(&self) -> Unique<T> {
+        let values = self.to_vec();
+        let mut order = (0..values.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| nan_last_cmp(&values[a], &values[b]));
+
+        let mut distinct: Vec<T> = Vec::new();
+        let mut counts = Vec::new();
+        let mut inverse = vec![0i64; values.len()];
+        for i in order {
+            if distinct.last() == Some(&values[i]) {
+                *counts.last_mut().unwrap() += 1;
+            } else {
+                distinct.push(values[i]);
+                counts.push(1i64);
+            }
+            inverse[i] = distinct.len() as i64 - 1;
+        }
+
+        Unique {
+            values: Tensor::from(distinct),
+            inverse: Tensor::from_vec(inverse, self.shape()),
+            counts: Tensor::from(counts),
+        }
+    }
+
+    /// Median along `dim` together with its index. An even-length lane gives the lower of its
+    /// two middle elements, and a lane containing `NaN` gives its first `NaN`.
+    pub fn median(&self, dim: usize, keepdim: bool) -> Result<(Tensor<T>, Tensor<i64>)> {
+        check_dim(dim, self.ndim())?;
+        if self.shape()[dim] == 0 {
+            return Err(TensorError::EmptyReduction { dim });
+        }
+
+        let reduced = self.reduce_dims(&[dim], keepdim, |lane| {
+            if let Some(i) = lane.iter().position(|x| x.partial_cmp(x).is_none()) {
+                return (lane[i], i as i64);
+            }
+            let mut order = (0..lane.len()).collect::<Vec<_>>();
+            order.sort_by(|&a, &b| lane[a].partial_cmp(&lane[b]).unwrap());
+            let middle = order[(lane.len() - 1) / 2];
+            (lane[middle], middle as i64)
+        })?;
+
+        let mut values = reduced.map(|(value, _)| value);
+        values.requires_grad = self.requires_grad;
+        Ok((values, reduced.map(|(_, index)| index)))
+    }
+
+    /// Reduces `dim` to the element that compares as `wins` against all others.
+    fn extreme_dim(
+        &self,
+        dim: usize,
+        keepdim: bool,
+        wins: Ordering,
+    ) -> Result<(Tensor<T>, Tensor<i64>)> {
+        check_dim(dim, self.ndim())?;
+        if self.shape()[dim] == 0 {
+            return Err(TensorError::EmptyReduction { dim });
+        }
+
+        let is_nan = |x: &T| x.partial_cmp(x).is_none();
+        let reduced = self.reduce_dims(&[dim], keepdim, |lane| {
+            let mut best = 0;
+            for (i, x) in lane.iter().enumerate().skip(1) {
+                if is_nan(&lane[best]) {
+                    break;
+                }
+                if is_nan(x) || x.partial_cmp(&lane[best]) == Some(wins) {
+                    best = i;
+                }
+            }
+            (lane[best], best as i64)
+        })?;
+
+        let mut values = reduced.map(|(value, _)| value);
+        values.requires_grad = self.requires_grad;
+        Ok((values, reduced.map(|(_, index)| index)))
+    }
+
+    /// Running extreme along `dim`, where `replaces(candidate, best)` decides whether the
+    /// next element takes over.
+    fn cumulative_extreme(
+        &self,
+        dim: usize,
+        replaces: impl Fn(T, T) -> bool,
+    ) -> Result<(Tensor<T>, Tensor<i64>)> {
+        let is_nan = |x: T| x.partial_cmp(&x).is_none();
+        let scanned = self.map(|x| (x, 0i64)).map_lanes(dim, |lane| {
+            for i in 1..lane.len() {
+                let (best, best_index) = lane[i - 1];
+                let candidate = lane[i].0;
+                lane[i] = if !is_nan(best) && (is_nan(candidate) || replaces(candidate, best)) {
+                    (candidate, i as i64)
+                } else {
+                    (best, best_index)
+                };
+            }
+        })?;
+
+        let mut indices = scanned.map(|(_, index)| index);
+        indices.requires_grad = false;
+        Ok((scanned.map(|(value, _)| value), indices))
+    }
+}
+
+/// Mean of a lane, `NaN` when it is empty.
+fn lane_mean<T: Float>(lane: &[T]) -> T {
+    let sum = lane.iter().fold(T::zero(), |acc, &x| acc + x);
+    sum / T::from(lane.len()).unwrap()
+}
+
+/// Variance of a lane around its mean, divided by `n - 1` when `unbiased` is set. `NaN` when
+/// the divisor is not positive.
+fn lane_var<T: Float>(lane: &[T], unbiased: bool) -> T {
+    let mean = lane_mean(lane);
+    let squares = lane
+        .iter()
+        .fold(T::zero(), |acc, &x| acc + (x - mean) * (x - mean));
+    let divisor = lane.len() as f64 - if unbiased { 1.0 } else { 0.0 };
+    if divisor <= 0.0 {
+        return T::nan();
+    }
+    squares / T::from(divisor).unwrap()
+}
+
+/// Sum of a lane, skipping `NaN`s.
+fn lane_nansum<T: Float>(lane: &[T]) -> T {
+    lane.iter()
+        .filter(|x| !x.is_nan())
+        .fold(T::zero(), |acc, &x| acc + x)
+}
+
+/// Mean of the elements of a lane that are not `NaN`.
+fn lane_nanmean<T: Float>(lane: &[T]) -> T {
+    let count = lane.iter().filter(|x| !x.is_nan()).count();
+    lane_nansum(lane) / T::from(count).unwrap()
+}
+
+/// How [`Tensor::quantile`] picks a value when the quantile falls between two elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuantileInterpolation {
+    /// Linear interpolation between the two neighbours.
+    Linear,
+    /// The smaller neighbour.
+    Lower,
+    /// The larger neighbour.
+    Higher,
+    /// The closer neighbour, rounding half-way positions to the even index.
+    Nearest,
+    /// The mean of the two neighbours.
+    Midpoint,
+}
+
+/// Quantile `q` of a lane, `NaN` when it is empty or contains `NaN`.
+fn lane_quantile<T: Float>(lane: &[T], q: f64, interpolation: QuantileInterpolation) -> T {
+    if lane.is_empty() || lane.iter().any(|x| x.is_nan()) {
+        return T::nan();
+    }
+    let mut sorted = lane.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let position = q * (sorted.len() - 1) as f64;
+    let (below, above) = (
+        sorted[position.floor() as usize],
+        sorted[position.ceil() as usize],
+    );
+    match interpolation {
+        QuantileInterpolation::Linear => {
+            below + (above - below) * T::from(position.fract()).unwrap()
+        }
+        QuantileInterpolation::Lower => below,
+        QuantileInterpolation::Higher => above,
+        QuantileInterpolation::Nearest => sorted[position.round_ties_even() as usize],
+        QuantileInterpolation::Midpoint => (below + above) / T::from(2.0).unwrap(),
+    }
+}
+
+/// `p`-norm of a lane. Infinite `p` picks the largest (or smallest) magnitude and zero
+/// counts the non-zero elements; otherwise the magnitudes are scaled by their maximum so
+/// large values cannot overflow.
+pub(crate) fn lane_norm<T: Float>(lane: &[T], p: T) -> T {
+    if lane.iter().any(|x| x.is_nan()) {
+        return T::nan();
+    }
+    let magnitudes = lane.iter().map(|x| x.abs());
+    if p == T::infinity() {
+        return magnitudes.fold(T::zero(), T::max);
+    }
+    if p == T::neg_infinity() {
+        return magnitudes.fold(T::infinity(), T::min);
+    }
+    if p.is_zero() {
+        return T::from(lane.iter().filter(|x| !x.is_zero()).count()).unwrap();
+    }
+
+    let scale = lane_norm(lane, T::infinity());
+    if scale.is_zero() || scale.is_infinite() {
+        return scale;
+    }
+    let sum = magnitudes.fold(T::zero(), |acc, x| acc + (x / scale).powf(p));
+    scale * sum.powf(p.recip())
+}
+
+impl<T: Float> Tensor<T> {
+    /// Arithmetic mean of every element as a zero-dimensional tensor. `NaN` when empty.
+    pub fn mean(&self) -> Tensor<T> {
+        self.reduce_all(lane_mean)
+    }
+
+    /// Arithmetic mean over the dimensions `dims`.
+    pub fn mean_dim(&self, dims: &[usize], keepdim: bool) -> Result<Tensor<T>> {
+        self.reduce_float(dims, keepdim, lane_mean)
+    }
+
+    /// Variance of every element, with Bessel's correction (dividing by `n - 1`) when
+    /// `unbiased` is set.
+    pub fn var(&self, unbiased: bool) -> Tensor<T> {
+        self.reduce_all(|lane| lane_var(lane, unbiased))
+    }
+
+    /// Variance over the dimensions `dims`; see [`Tensor::var`].
+    pub fn var_dim(&self, dims: &[usize], unbiased: bool, keepdim: bool) -> Result<Tensor<T>> {
+        self.reduce_float(dims, keepdim, |lane| lane_var(lane, unbiased))
+    }
+
+    /// Standard deviation of every element, the square root of [`Tensor::var`].
+    pub fn std(&self, unbiased: bool) -> Tensor<T> {
+        self.reduce_all(|lane| lane_var(lane, unbiased).sqrt())
+    }
+
+    /// Standard deviation over the dimensions `dims`; see [`Tensor::std`].
+    pub fn std_dim(&self, dims: &[usize], unbiased: bool, keepdim: bool) -> Result<Tensor<T>> {
+        self.reduce_float(dims, keepdim, |lane| lane_var(lane, unbiased).sqrt())
+    }
+
+    /// `p`-norm of every element: `(sum |x|^p)^(1/p)`, the largest magnitude for `p = inf`,
+    /// the smallest for `p = -inf`, and the number of non-zero elements for `p = 0`.
+    pub fn norm(&self, p: T) -> Tensor<T> {
+        self.reduce_all(|lane| lane_norm(lane, p))
+    }
+
+    /// `p`-norm over the dimensions `dims`; see [`Tensor::norm`].
+    pub fn norm_dim(&self, p: T, dims: &[usize], keepdim: bool) -> Result<Tensor<T>> {
+        self.reduce_float(dims, keepdim, |lane| lane_norm(lane, p))
+    }
+
+    /// Frobenius norm of each matrix in the last two dimensions, the 2-norm of its entries.
+    pub fn frobenius_norm(&self) -> Result<Tensor<T>> {
+        let ndim = self.ndim();
+        if ndim < 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: ndim,
+            });
+        }
+        self.norm_dim(T::from(2.0).unwrap(), &[ndim - 2, ndim - 1], false)
+    }
+
+    /// The `q`-th quantile along `dim` of the sorted lane, interpolating between neighbours
+    /// as `interpolation` says. A lane containing `NaN` gives `NaN`.
+    ///
+    /// Fails with [`TensorError::ArgumentOutOfRange`] if `q` is not in `[0, 1]`.
+    pub fn quantile(
+        &self,
+        q: f64,
+        dim: usize,
+        keepdim: bool,
+        interpolation: QuantileInterpolation,
+    ) -> Result<Tensor<T>> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(TensorError::ArgumentOutOfRange {
+                name: "q",
+                range: "[0, 1]",
+                value: q.to_string(),
+            });
+        }
+        self.reduce_float(&[dim], keepdim, |lane| {
+            lane_quantile(lane, q, interpolation)
+        })
+    }
+
+    /// [`Tensor::quantile`] with `p` given as a percentage in `[0, 100]`.
+    pub fn percentile(
+        &self,
+        p: f64,
+        dim: usize,
+        keepdim: bool,
+        interpolation: QuantileInterpolation,
+    ) -> Result<Tensor<T>> {
+        if !(0.0..=100.0).contains(&p) {
+            return Err(TensorError::ArgumentOutOfRange {
+                name: "p",
+                range: "[0, 100]",
+                value: p.to_string(),
+            });
+        }
+        self.quantile(p / 100.0, dim, keepdim, interpolation)
+    }
+
+    /// Histogram of every element over `bins` equal-width bins spanning `[min, max]`. The last
+    /// bin includes `max`; elements outside the range and `NaN`s are not counted. When
+    /// `min == max` the range of the data is used instead.
+    ///
+    /// Panics if `bins` is zero.
+    pub fn histc(&self, bins: usize, min: T, max: T) -> Tensor<T> {
+        if bins == 0 {
+            panic!("Tensor::histc bins must be non-zero");
+        }
+        let values = self.to_vec();
+        let (mut min, mut max) = (min, max);
+        if min == max {
+            let numbers = values.iter().copied().filter(|x| !x.is_nan());
+            min = numbers.clone().fold(T::infinity(), T::min);
+            max = numbers.fold(T::neg_infinity(), T::max);
+            if min > max {
+                // No numbers at all.
+                (min, max) = (T::zero(), T::zero());
+            }
+            if min == max {
+                (min, max) = (min - T::one(), max + T::one());
+            }
+        }
+
+        let width = T::from(bins).unwrap() / (max - min);
+        let mut counts = vec![T::zero(); bins];
+        for x in values {
+            if x >= min && x <= max {
+                let bin = ((x - min) * width).to_usize().unwrap_or(0).min(bins - 1);
+                counts[bin] = counts[bin] + T::one();
+            }
+        }
+        Tensor::from(counts)
+    }
+
+    /// Covariance matrix of a 2-D tensor whose rows are variables and columns observations,
+    /// divided by `n - 1` when `unbiased` is set.
+    pub fn cov(&self, unbiased: bool) -> Result<Tensor<T>> {
+        if self.ndim() != 2 {
+            return Err(TensorError::RankMismatch {
+                expected: 2,
+                actual: self.ndim(),
+            });
+        }
+        let (vars, observations) = (self.shape()[0], self.shape()[1]);
+        let values = self.to_vec();
+        let centered = values
+            .chunks(observations.max(1))
+            .take(vars)
+            .flat_map(|row| {
+                let mean = lane_mean(row);
+                row.iter().map(move |&x| x - mean)
+            })
+            .collect::<Vec<_>>();
+
+        let divisor = observations as f64 - if unbiased { 1.0 } else { 0.0 };
+        let divisor = if divisor > 0.0 {
+            T::from(divisor).unwrap()
+        } else {
+            T::nan()
+        };
+        let row = |i: usize| &centered[i * observations..(i + 1) * observations];
+        let mut data = Vec::with_capacity(vars * vars);
+        for i in 0..vars {
+            for j in 0..vars {
+                let dot = row(i)
+                    .iter()
+                    .zip(row(j))
+                    .fold(T::zero(), |acc, (&a, &b)| acc + a * b);
+                data.push(dot / divisor);
+            }
+        }
+        Ok(Tensor::from_vec(data, &[vars, vars]))
+    }
+
+    /// Pearson correlation coefficients between the rows of a 2-D tensor, clamped to
+    /// `[-1, 1]`. A constant row correlates as `NaN`.
+    pub fn corrcoef(&self) -> Result<Tensor<T>> {
+        let cov = self.cov(false)?.to_vec();
+        let vars = self.shape()[0];
+        let data = (0..vars * vars)
+            .map(|k| {
+                let (i, j) = (k / vars, k % vars);
+                let scale = (cov[i * vars + i] * cov[j * vars + j]).sqrt();
+                clamp_value(cov[k] / scale, Some(-T::one()), Some(T::one()))
+            })
+            .collect();
+        Ok(Tensor::from_vec(data, &[vars, vars]))
+    }
+
+    /// Sum of every element, treating `NaN`s as zero.
+    pub fn nansum(&self) -> Tensor<T> {
+        self.reduce_all(lane_nansum)
+    }
+
+    /// Sum over the dimensions `dims`, treating `NaN`s as zero.
+    pub fn nansum_dim(&self, dims: &[usize], keepdim: bool) -> Result<Tensor<T>> {
+        self.reduce_float(dims, keepdim, lane_nansum)
+    }
+
+    /// Mean of the elements that are not `NaN`. `NaN` when there are none.
+    pub fn nanmean(&self) -> Tensor<T> {
+        self.reduce_all(lane_nanmean)
+    }
+
+    /// Mean over the dimensions `dims` of the elements that are not `NaN`.
+    pub fn nanmean_dim(&self, dims: &[usize], keepdim: bool) -> Result<Tensor<T>> {
+        self.reduce_float(dims, keepdim, lane_nanmean)
+    }
+
+    /// `sum(w * x) / sum(w)` along `dim`, where `weights` broadcasts to this tensor's shape.
+    /// Pairs in which the element or its weight is `NaN` are left out.
+    pub fn weighted_mean(
+        &self,
+        weights: &Tensor<T>,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<Tensor<T>> {
+        let pairs = self.zip_map(weights, |x, w| (x, w))?;
+        if pairs.shape() != self.shape() {
+            return Err(TensorError::BroadcastMismatch {
+                left: self.shape().to_vec(),
+                right: weights.shape().to_vec(),
+            });
+        }
+
+        let mut result = pairs.reduce_dims(&[dim], keepdim, |lane| {
+            let (total, weight) = lane
+                .iter()
+                .filter(|(x, w)| !x.is_nan() && !w.is_nan())
+                .fold((T::zero(), T::zero()), |(total, weight), &(x, w)| {
+                    (total + w * x, weight + w)
+                });
+            total / weight
+        })?;
+        result.requires_grad = self.requires_grad || weights.requires_grad;
+        Ok(result)
+    }
+
+    fn reduce_all(&self, f: impl FnOnce(&[T]) -> T) -> Tensor<T> {
+        let mut result = Tensor::scalar(f(&self.to_vec()));
+        result.requires_grad = self.requires_grad;
+        result
+    }
+
+    fn reduce_float(
+        &self,
+        dims: &[usize],
+        keepdim: bool,
+        f: impl FnMut(&[T]) -> T,
+    ) -> Result<Tensor<T>> {
+        let mut result = self.reduce_dims(dims, keepdim, f)?;
+        result.requires_grad = self.requires_grad;
+        Ok(result)
+    }
+
+    /// `ln(sum(e^x))` over `dim`, with the lane maximum factored out so the exponentials
+    /// cannot overflow. An all `-inf` lane gives `-inf`.
+    pub fn logsumexp(&self, dim: usize, keepdim: bool) -> Result<Tensor<T>> {
+        self.reduce_float(&[dim], keepdim, |lane| {
+            let max = lane.iter().fold(T::neg_infinity(), |acc, &x| acc.max(x));
+            if max.is_infinite() {
+                return max;
+            }
+            let sum = lane.iter().fold(T::zero(), |acc, &x| acc + (x - max).exp());
+            max + sum.ln()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{QuantileInterpolation, prod_backward, prod_dim_backward};
+    use crate::error::TensorError;
+    use crate::types::Tensor;
+
+    #[test]
+    fn reduce_dims_shapes() {
+        let t = Tensor::arange(0, 24, 1).reshape(&[2, 3, 4]).unwrap();
+
+        let lanes = t.reduce_dims(&[1], false, |lane| lane.to_vec()).unwrap();
+        let kept = t.reduce_dims(&[0, 2], true, |lane| lane.len()).unwrap();
+
+        assert_eq!(lanes.shape(), &[2, 4]);
+        assert_eq!(lanes.to_vec()[1], vec![1, 5, 9]);
+        assert_eq!(kept.shape(), &[1, 3, 1]);
+        assert_eq!(kept.to_vec(), vec![8, 8, 8]);
+        assert!(t.reduce_dims(&[1, 1], false, |lane| lane.len()).is_err());
+    }
+
+    #[test]
+    fn logsumexp() {
+        let mut t = crate::tensor![[1000.0, 1000.0], [f64::NEG_INFINITY, f64::NEG_INFINITY]];
+        t.requires_grad = true;
+
+        let reduced = t.logsumexp(1, false).unwrap();
+        let kept = Tensor::from(vec![0.0, 0.0]).logsumexp(0, true).unwrap();
+
+        assert_eq!(reduced.shape(), &[2]);
+        assert!(reduced.requires_grad);
+        assert_eq!(
+            reduced.to_vec(),
+            vec![1000.0 + 2f64.ln(), f64::NEG_INFINITY]
+        );
+        assert_eq!(kept.shape(), &[1]);
+        assert_eq!(kept.to_vec(), vec![2f64.ln()]);
+    }
+
+    #[test]
+    fn sum() {
+        let t = Tensor::arange(0, 24, 1).reshape(&[2, 3, 4]).unwrap();
+        let strided = t.transpose(0, 2).unwrap();
+
+        assert_eq!(t.sum().ndim(), 0);
+        assert_eq!(t.sum().to_vec(), vec![276]);
+        assert_eq!(Tensor::<f32>::zeros(&[0, 3]).sum().to_vec(), vec![0.0]);
+        assert_eq!(
+            t.sum_dim(&[0, 2], false).unwrap().to_vec(),
+            vec![60, 92, 124]
+        );
+        assert_eq!(strided.sum_dim(&[2], true).unwrap().shape(), &[4, 3, 1]);
+        assert_eq!(
+            strided.sum_dim(&[2], false).unwrap().to_vec()[..3],
+            [12, 20, 28]
+        );
+        assert!(t.sum_dim(&[3], false).is_err());
+    }
+
+    #[test]
+    fn mean_var_std() {
+        let t = crate::tensor![[1.0f64, 2.0, 3.0, 4.0], [2.0, 2.0, 2.0, 2.0]];
+
+        assert_eq!(t.mean().to_vec(), vec![2.25]);
+        assert_eq!(t.mean_dim(&[1], false).unwrap().to_vec(), vec![2.5, 2.0]);
+        assert_eq!(
+            t.var_dim(&[1], false, true).unwrap().to_vec(),
+            vec![1.25, 0.0]
+        );
+        assert_eq!(t.var_dim(&[1], true, false).unwrap().to_vec()[0], 5.0 / 3.0);
+        assert_eq!(t.std_dim(&[0], false, false).unwrap().to_vec()[3], 1.0);
+        assert!((t.std(true).to_vec()[0] - (5.5f64 / 7.0).sqrt()).abs() < 1e-12);
+        assert!(Tensor::from(vec![1.0f64]).var(true).to_vec()[0].is_nan());
+        assert!(Tensor::<f64>::zeros(&[0]).mean().to_vec()[0].is_nan());
+    }
+
+    #[test]
+    fn max_and_min_dim() {
+        let t = crate::tensor![[1.0, 5.0, 5.0], [f64::NAN, 2.0, -1.0]];
+
+        let (max, max_at) = t.max_dim(1, false).unwrap();
+        let (min, min_at) = t.min_dim(0, true).unwrap();
+
+        assert_eq!(max.to_vec()[0], 5.0);
+        assert!(max.to_vec()[1].is_nan());
+        assert_eq!(max_at.to_vec(), vec![1, 0]);
+        assert_eq!(min.shape(), &[1, 3]);
+        assert_eq!(min_at.to_vec(), vec![1, 1, 1]);
+        assert_eq!(t.argmin(1, false).unwrap().to_vec(), vec![0, 0]);
+        assert_eq!(
+            Tensor::from(vec![3, 9, 9])
+                .argmax(0, false)
+                .unwrap()
+                .to_vec(),
+            vec![1]
+        );
+        assert_eq!(
+            Tensor::<i32>::zeros(&[2, 0]).argmax(1, false).err(),
+            Some(TensorError::EmptyReduction { dim: 1 })
+        );
+    }
+
+    #[test]
+    fn prod() {
+        let t = crate::tensor![[1, 2, 3], [4, 0, 6]];
+
+        assert_eq!(t.prod().to_vec(), vec![0]);
+        assert_eq!(Tensor::<i32>::zeros(&[0]).prod().to_vec(), vec![1]);
+        assert_eq!(t.prod_dim(1, false).unwrap().to_vec(), vec![6, 0]);
+        assert_eq!(t.prod_dim(0, true).unwrap().shape(), &[1, 3]);
+        assert_eq!(t.prod_dim(0, true).unwrap().to_vec(), vec![4, 0, 18]);
+    }
+
+    #[test]
+    fn prod_backward_with_zeros() {
+        let t = crate::tensor![[2.0f64, 3.0, 4.0], [0.0, 5.0, 0.0], [0.0, 2.0, 3.0]];
+
+        let grad = prod_dim_backward(&t, &Tensor::from(vec![1.0, 1.0, 2.0]), 1, false).unwrap();
+        let full = prod_backward(&Tensor::from(vec![2.0, 0.0, 3.0]), &Tensor::scalar(1.0));
+
+        assert_eq!(
+            grad.to_vec(),
+            vec![12.0, 8.0, 6.0, 0.0, 0.0, 0.0, 12.0, 0.0, 0.0]
+        );
+        assert_eq!(full.unwrap().to_vec(), vec![0.0, 6.0, 0.0]);
+        assert!(prod_dim_backward(&t, &Tensor::from(vec![1.0; 3]), 1, true).is_err());
+    }
+
+    #[test]
+    fn any_and_all() {
+        let mask = crate::tensor![[true, false], [true, true]];
+        let numbers = crate::tensor![[0.0, 0.0], [1.5, -2.0]];
+
+        assert!(mask.any());
+        assert!(!mask.all());
+        assert!(!numbers.any_dim(1, false).unwrap().to_vec()[0]);
+        assert_eq!(mask.all_dim(0, false).unwrap().to_vec(), vec![true, false]);
+        assert_eq!(numbers.all_dim(1, true).unwrap().shape(), &[2, 1]);
+        assert_eq!(
+            numbers.all_dim(1, true).unwrap().to_vec(),
+            vec![false, true]
+        );
+        assert!(Tensor::<i32>::zeros(&[0]).all());
+        assert!(!Tensor::<bool>::full(&[0], true).any());
+    }
+
+    #[test]
+    fn norms() {
+        let t = crate::tensor![[3.0f64, -4.0], [0.0, 1e300]];
+
+        assert_eq!(
+            t.norm_dim(1.0, &[1], false).unwrap().to_vec(),
+            vec![7.0, 1e300]
+        );
+        assert_eq!(
+            t.norm_dim(2.0, &[1], true).unwrap().to_vec(),
+            vec![5.0, 1e300]
+        );
+        assert_eq!(t.norm(f64::INFINITY).to_vec(), vec![1e300]);
+        assert_eq!(t.norm(f64::NEG_INFINITY).to_vec(), vec![0.0]);
+        assert_eq!(t.norm(0.0).to_vec(), vec![3.0]);
+        assert!(
+            (Tensor::from(vec![1.0f64, 2.0]).norm(3.0).to_vec()[0] - 9f64.cbrt()).abs() < 1e-12
+        );
+        assert_eq!(
+            crate::tensor![[1.0f64, 2.0], [2.0, 4.0]]
+                .frobenius_norm()
+                .unwrap()
+                .to_vec(),
+            vec![5.0]
+        );
+        assert!(Tensor::from(vec![1.0f64]).frobenius_norm().is_err());
+    }
+
+    #[test]
+    fn median() {
+        let t = crate::tensor![[3.0, 1.0, 4.0, 2.0], [5.0, f64::NAN, 0.0, f64::NAN]];
+
+        let (values, indices) = t.median(1, false).unwrap();
+        let (column, at) = Tensor::from(vec![7, 3, 5]).median(0, true).unwrap();
+
+        assert_eq!(values.to_vec()[0], 2.0);
+        assert!(values.to_vec()[1].is_nan());
+        assert_eq!(indices.to_vec(), vec![3, 1]);
+        assert_eq!(column.shape(), &[1]);
+        assert_eq!((column.to_vec(), at.to_vec()), (vec![5], vec![2]));
+    }
+
+    #[test]
+    fn quantile_interpolation() {
+        let t = Tensor::from(vec![4.0f64, 1.0, 3.0, 2.0]);
+        let at = |q, mode| t.quantile(q, 0, false, mode).unwrap().to_vec()[0];
+
+        assert_eq!(at(0.5, QuantileInterpolation::Linear), 2.5);
+        assert_eq!(at(0.4, QuantileInterpolation::Linear), 2.2);
+        assert_eq!(at(0.4, QuantileInterpolation::Lower), 2.0);
+        assert_eq!(at(0.4, QuantileInterpolation::Higher), 3.0);
+        assert_eq!(at(0.5, QuantileInterpolation::Nearest), 3.0);
+        assert_eq!(at(0.4, QuantileInterpolation::Midpoint), 2.5);
+        assert_eq!(at(1.0, QuantileInterpolation::Linear), 4.0);
+        assert_eq!(
+            t.percentile(25.0, 0, true, QuantileInterpolation::Linear)
+                .unwrap()
+                .to_vec(),
+            vec![1.75]
+        );
+        assert!(matches!(
+            t.quantile(f64::NAN, 0, false, QuantileInterpolation::Linear),
+            Err(TensorError::ArgumentOutOfRange { name: "q", value, .. }) if value == "NaN"
+        ));
+        assert!(matches!(
+            t.percentile(150.0, 0, false, QuantileInterpolation::Linear),
+            Err(TensorError::ArgumentOutOfRange { name: "p", .. })
+        ));
+    }
+
+    #[test]
+    fn histc() {
+        let t = Tensor::from(vec![0.0f64, 0.5, 1.0, 2.5, 4.0, 5.0, f64::NAN]);
+
+        assert_eq!(t.histc(4, 0.0, 4.0).to_vec(), vec![2.0, 1.0, 1.0, 1.0]);
+        assert_eq!(t.histc(2, 0.0, 0.0).to_vec(), vec![3.0, 3.0]);
+        assert_eq!(
+            Tensor::from(vec![3.0f64]).histc(2, 0.0, 0.0).to_vec(),
+            vec![0.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn bincount() {
+        let t = Tensor::from(vec![1i64, 3, 1, 0]);
+
+        assert_eq!(t.bincount(0).unwrap().to_vec(), vec![1, 2, 0, 1]);
+        assert_eq!(t.bincount(6).unwrap().to_vec(), vec![1, 2, 0, 1, 0, 0]);
+        assert_eq!(
+            t.bincount_weighted(&Tensor::from(vec![0.5, 1.0, 2.0, 4.0]), 0)
+                .unwrap()
+                .to_vec(),
+            vec![4.0, 2.5, 0.0, 1.0]
+        );
+        assert_eq!(
+            Tensor::from(vec![2i64, -1]).bincount(0).err(),
+            Some(TensorError::NegativeValue { value: -1 })
+        );
+        assert_eq!(Tensor::<i64>::zeros(&[0]).bincount(0).unwrap().numel(), 0);
+    }
+
+    #[test]
+    fn cov_and_corrcoef() {
+        let t = crate::tensor![[1.0f64, 2.0, 3.0], [6.0, 4.0, 2.0], [5.0, 5.0, 5.0]];
+
+        let cov = t.cov(true).unwrap();
+        let corr = t.corrcoef().unwrap().to_vec();
+
+        assert_eq!(cov.shape(), &[3, 3]);
+        assert_eq!(cov.to_vec()[..3], [1.0, -2.0, 0.0]);
+        assert_eq!(cov.to_vec()[4], 4.0);
+        assert_eq!(t.cov(false).unwrap().to_vec()[0], 2.0 / 3.0);
+        assert_eq!(corr[..2], [1.0, -1.0]);
+        assert!(corr[8].is_nan());
+        assert!(Tensor::from(vec![1.0f64]).cov(true).is_err());
+    }
+
+    #[test]
+    fn count_nonzero() {
+        let t = crate::tensor![[0, 3, 0], [1, 2, 0]];
+
+        assert_eq!(t.count_nonzero(), 3);
+        assert_eq!(
+            t.count_nonzero_dim(&[0], false).unwrap().to_vec(),
+            vec![1, 2, 0]
+        );
+        assert_eq!(t.count_nonzero_dim(&[0, 1], true).unwrap().shape(), &[1, 1]);
+    }
+
+    #[test]
+    fn unique() {
+        let t = crate::tensor![[3, 1, 3], [2, 1, 3]];
+        let floats = Tensor::from(vec![f64::NAN, 1.0, f64::NAN]);
+
+        let unique = t.unique();
+
+        assert_eq!(unique.values.to_vec(), vec![1, 2, 3]);
+        assert_eq!(unique.counts.to_vec(), vec![2, 1, 3]);
+        assert_eq!(unique.inverse.shape(), &[2, 3]);
+        assert_eq!(unique.inverse.to_vec(), vec![2, 0, 2, 1, 0, 2]);
+        assert_eq!(floats.unique().counts.to_vec(), vec![1, 1, 1]);
+        assert_eq!(floats.unique().values.to_vec()[0], 1.0);
+    }
+
+    #[test]
+    fn nan_aware_reductions() {
+        let t = crate::tensor![[1.0, f64::NAN, 3.0], [f64::NAN, f64::NAN, 4.0]];
+
+        assert_eq!(t.nansum().to_vec(), vec![8.0]);
+        assert_eq!(t.nanmean().to_vec(), vec![8.0 / 3.0]);
+        assert_eq!(
+            t.nansum_dim(&[0], false).unwrap().to_vec(),
+            vec![1.0, 0.0, 7.0]
+        );
+        let means = t.nanmean_dim(&[0], true).unwrap().to_vec();
+        assert_eq!((means[0], means[2]), (1.0, 3.5));
+        assert!(means[1].is_nan());
+    }
+
+    #[test]
+    fn weighted_mean() {
+        let t = crate::tensor![[1.0, 2.0, f64::NAN], [4.0, 0.0, 8.0]];
+        let weights = Tensor::from(vec![3.0, 1.0, 2.0]);
+
+        assert_eq!(
+            t.weighted_mean(&weights, 1, false).unwrap().to_vec(),
+            vec![1.25, 28.0 / 6.0]
+        );
+        assert_eq!(
+            t.weighted_mean(&crate::tensor![[1.0], [3.0]], 0, true)
+                .unwrap()
+                .to_vec(),
+            vec![3.25, 0.5, 8.0]
+        );
+        assert!(weights.weighted_mean(&t, 0, false).is_err());
+    }
+
+    #[test]
+    fn trace() {
+        let t = Tensor::arange(0, 6, 1).reshape(&[2, 3]).unwrap();
+
+        assert_eq!(t.trace().unwrap().to_vec(), vec![4]);
+        assert_eq!(
+            t.transpose(0, 1).unwrap().trace().unwrap().to_vec(),
+            vec![4]
+        );
+        assert!(Tensor::from(vec![1, 2]).trace().is_err());
+    }
+
+    #[test]
+    fn cumsum_and_cumprod() {
+        let t = crate::tensor![[1, 2, 3], [4, 5, 6]];
+
+        assert_eq!(t.cumsum(1).unwrap().to_vec(), vec![1, 3, 6, 4, 9, 15]);
+        assert_eq!(t.cumsum(0).unwrap().to_vec(), vec![1, 2, 3, 5, 7, 9]);
+        assert_eq!(t.cumprod(1).unwrap().to_vec(), vec![1, 2, 6, 4, 20, 120]);
+        assert!(t.cumsum(2).is_err());
+    }
+
+    #[test]
+    fn cummax_and_cummin() {
+        let t = Tensor::from(vec![1.0, 3.0, 2.0, 3.0, f64::NAN, 5.0]);
+
+        let (max, max_at) = t.cummax(0).unwrap();
+        let (min, min_at) = Tensor::from(vec![2, 1, 3, 1]).cummin(0).unwrap();
+
+        assert_eq!(max.to_vec()[..4], [1.0, 3.0, 3.0, 3.0]);
+        assert!(max.to_vec()[4..].iter().all(|x| x.is_nan()));
+        assert_eq!(max_at.to_vec(), vec![0, 1, 1, 3, 4, 4]);
+        assert_eq!(min.to_vec(), vec![2, 1, 1, 1]);
+        assert_eq!(min_at.to_vec(), vec![0, 1, 1, 3]);
+    }
+}
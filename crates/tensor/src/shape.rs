@@ -0,0 +1,195 @@
+use crate::error::{Result, TensorError};
+use std::ops::{Deref, DerefMut};
+
+/// Sizes of each dimension of a tensor, outermost first.
+///
+/// Dereferences to `[usize]`, so it can be indexed and iterated like a slice. The methods
+/// gather the layout arithmetic (element counts, default strides, broadcasting, and view
+/// bounds) that every operation relies on.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Shape(Vec<usize>);
+
+impl Shape {
+    pub fn new(dims: Vec<usize>) -> Self {
+        Shape(dims)
+    }
+
+    pub fn ndim(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Total number of elements. Use [`Shape::checked_numel`] for untrusted shapes.
+    pub fn numel(&self) -> usize {
+        self.0.iter().product()
+    }
+
+    /// Total number of elements, or `None` if it overflows `usize`.
+    pub fn checked_numel(&self) -> Option<usize> {
+        self.0
+            .iter()
+            .try_fold(1usize, |acc, &dim| acc.checked_mul(dim))
+    }
+
+    /// Row-major (C-order) strides for a contiguous tensor of this shape.
+    pub fn default_strides(&self) -> Vec<isize> {
+        let mut strides = vec![0; self.0.len()];
+        let mut stride = 1;
+        for (i, &dim) in self.0.iter().enumerate().rev() {
+            strides[i] = stride as isize;
+            stride *= dim.max(1);
+        }
+        strides
+    }
+
+    /// The shape this shape and `other` broadcast to under NumPy rules: dimensions are
+    /// aligned from the right, and each pair must be equal or contain a 1.
+    pub fn broadcast_with(&self, other: &Shape) -> Result<Shape> {
+        let ndim = self.ndim().max(other.ndim());
+        let dim_at = |shape: &Shape, i: usize| match (i + shape.ndim()).checked_sub(ndim) {
+            Some(j) => shape[j],
+            None => 1,
+        };
+
+        (0..ndim)
+            .map(|i| match (dim_at(self, i), dim_at(other, i)) {
+                (l, r) if l == r || r == 1 => Ok(l),
+                (1, r) => Ok(r),
+                _ => Err(TensorError::BroadcastMismatch {
+                    left: self.0.clone(),
+                    right: other.0.clone(),
+                }),
+            })
+            .collect()
+    }
+
+    /// Whether a view of this shape with the given `strides` and `offset` only touches
+    /// positions inside a buffer of `buffer_len` elements. Negative strides walk backwards
+    /// from `offset`, so they must not reach below index zero. Empty views always fit.
+    pub fn is_valid_view(&self, strides: &[isize], offset: usize, buffer_len: usize) -> bool {
+        if strides.len() != self.ndim() {
+            return false;
+        }
+        if self.0.contains(&0) {
+            return true;
+        }
+        // i128 cannot overflow here: every term is the product of two values below 2^64.
+        let (mut lowest, mut highest) = (offset as i128, offset as i128);
+        for (&dim, &stride) in self.0.iter().zip(strides) {
+            let extent = (dim as i128 - 1) * stride as i128;
+            if extent < 0 {
+                lowest += extent;
+            } else {
+                highest += extent;
+            }
+        }
+        lowest >= 0 && highest < buffer_len as i128
+    }
+
+    pub fn insert(&mut self, index: usize, dim: usize) {
+        self.0.insert(index, dim);
+    }
+
+    pub fn remove(&mut self, index: usize) -> usize {
+        self.0.remove(index)
+    }
+
+    pub fn push(&mut self, dim: usize) {
+        self.0.push(dim);
+    }
+
+    pub fn into_vec(self) -> Vec<usize> {
+        self.0
+    }
+}
+
+impl Deref for Shape {
+    type Target = [usize];
+
+    fn deref(&self) -> &[usize] {
+        &self.0
+    }
+}
+
+impl DerefMut for Shape {
+    fn deref_mut(&mut self) -> &mut [usize] {
+        &mut self.0
+    }
+}
+
+impl From<Vec<usize>> for Shape {
+    fn from(dims: Vec<usize>) -> Self {
+        Shape(dims)
+    }
+}
+
+impl From<&[usize]> for Shape {
+    fn from(dims: &[usize]) -> Self {
+        Shape(dims.to_vec())
+    }
+}
+
+impl<const N: usize> From<[usize; N]> for Shape {
+    fn from(dims: [usize; N]) -> Self {
+        Shape(dims.to_vec())
+    }
+}
+
+impl FromIterator<usize> for Shape {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        Shape(iter.into_iter().collect())
+    }
+}
+
+impl PartialEq<[usize]> for Shape {
+    fn eq(&self, other: &[usize]) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<Vec<usize>> for Shape {
+    fn eq(&self, other: &Vec<usize>) -> bool {
+        &self.0 == other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Shape;
+    use crate::error::TensorError;
+
+    #[test]
+    fn numel_and_default_strides() {
+        let shape = Shape::from([2, 3, 4]);
+
+        assert_eq!(shape.numel(), 24);
+        assert_eq!(shape.default_strides(), vec![12, 4, 1]);
+        assert_eq!(Shape::from([usize::MAX, 2]).checked_numel(), None);
+        assert_eq!(Shape::default().numel(), 1);
+    }
+
+    #[test]
+    fn broadcast_with() {
+        let broadcast = |l: &[usize], r: &[usize]| Shape::from(l).broadcast_with(&Shape::from(r));
+
+        assert_eq!(broadcast(&[4, 1], &[1, 5]), Ok(Shape::from([4, 5])));
+        assert_eq!(broadcast(&[2, 3], &[3]), Ok(Shape::from([2, 3])));
+        assert_eq!(
+            broadcast(&[3], &[4]),
+            Err(TensorError::BroadcastMismatch {
+                left: vec![3],
+                right: vec![4],
+            })
+        );
+    }
+
+    #[test]
+    fn is_valid_view() {
+        let shape = Shape::from([2, 2]);
+
+        assert!(shape.is_valid_view(&[2, 1], 0, 4));
+        assert!(!shape.is_valid_view(&[2, 1], 1, 4));
+        assert!(shape.is_valid_view(&[-2, 1], 2, 4));
+        assert!(!shape.is_valid_view(&[-2, 1], 1, 4));
+        assert!(!shape.is_valid_view(&[1], 0, 4));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::error::{Result, TensorError};
+use crate::reduce::nan_last_cmp;
+use crate::types::Tensor;
+use crate::view::check_dim;
+
+impl<T: PartialOrd + Copy> Tensor<T> {
+    /// Sorts every lane along `dim`, returning the sorted values and, for each, its index in
+    /// the original lane. The sort is stable, and `NaN`s order above every number.
+    pub fn sort(&self, dim: usize, descending: bool) -> Result<(Tensor<T>, Tensor<i64>)> {
+        let sorted = self.map(|x| (x, 0i64)).map_lanes(dim, |lane| {
+            for (i, (_, index)) in lane.iter_mut().enumerate() {
+                *index = i as i64;
+            }
+            if descending {
+                lane.sort_by(|a, b| nan_last_cmp(&b.0, &a.0));
+            } else {
+                lane.sort_by(|a, b| nan_last_cmp(&a.0, &b.0));
+            }
+        })?;
+
+        let mut indices = sorted.map(|(_, index)| index);
+        indices.requires_grad = false;
+        Ok((sorted.map(|(value, _)| value), indices))
+    }
+
+    /// The indices that would sort every lane along `dim`; see [`Tensor::sort`].
+    pub fn argsort(&self, dim: usize, descending: bool) -> Result<Tensor<i64>> {
+        Ok(self.sort(dim, descending)?.1)
+    }
+
+    /// The `k`-th smallest element (counting from 1) of every lane along `dim`, with its
+    /// index in the lane. Equal elements keep their original order and `NaN`s count as the
+    /// largest.
+    pub fn kthvalue(
+        &self,
+        k: usize,
+        dim: usize,
+        keepdim: bool,
+    ) -> Result<(Tensor<T>, Tensor<i64>)> {
+        check_dim(dim, self.ndim())?;
+        let size = self.shape()[dim];
+        if k == 0 || k > size {
+            return Err(TensorError::IndexOutOfRange {
+                index: k as i64,
+                size,
+            });
+        }
+        self.select_lanes(dim, keepdim, |lane| {
+            let mut order = (0..lane.len()).collect::<Vec<_>>();
+            order.sort_by(|&a, &b| nan_last_cmp(&lane[a], &lane[b]));
+            order[k - 1]
+        })
+    }
+
+    /// The most frequent element of every lane along `dim`, with the index of its first
+    /// occurrence. Ties go to the smallest value.
+    pub fn mode(&self, dim: usize, keepdim: bool) -> Result<(Tensor<T>, Tensor<i64>)> {
+        check_dim(dim, self.ndim())?;
+        if self.shape()[dim] == 0 {
+            return Err(TensorError::EmptyReduction { dim });
+        }
+        self.select_lanes(dim, keepdim, |lane| {
+            let mut order = (0..lane.len()).collect::<Vec<_>>();
+            order.sort_by(|&a, &b| nan_last_cmp(&lane[a], &lane[b]));
+
+            // Runs of equal values are contiguous in `order`, each led by its first occurrence.
+            let (mut best, mut best_len) = (order[0], 0);
+            let mut start = 0;
+            for end in 1..=order.len() {
+                if end == order.len() || lane[order[end]] != lane[order[start]] {
+                    if end - start > best_len {
+                        (best, best_len) = (order[start], end - start);
+                    }
+                    start = end;
+                }
+            }
+            best
+        })
+    }
+
+    /// Reduces `dim` to the element `pick` chooses from each lane, along with its index.
+    fn select_lanes(
+        &self,
+        dim: usize,
+        keepdim: bool,
+        mut pick: impl FnMut(&[T]) -> usize,
+    ) -> Result<(Tensor<T>, Tensor<i64>)> {
+        let reduced = self.reduce_dims(&[dim], keepdim, |lane| {
+            let i = pick(lane);
+            (lane[i], i as i64)
+        })?;
+
+        let mut values = reduced.map(|(value, _)| value);
+        values.requires_grad = self.requires_grad;
+        Ok((values, reduced.map(|(_, index)| index)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::TensorError;
+
+    #[test]
+    fn sort_along_dims() {
+        let t = crate::tensor![[3, 1, 2], [1, 1, 0]];
+
+        let (rows, row_indices) = t.sort(1, false).unwrap();
+        let (columns, column_indices) = t.sort(0, true).unwrap();
+
+        assert_eq!(rows.to_vec(), vec![1, 2, 3, 0, 1, 1]);
+        assert_eq!(row_indices.to_vec(), vec![1, 2, 0, 2, 0, 1]);
+        assert_eq!(columns.to_vec(), vec![3, 1, 2, 1, 1, 0]);
+        assert_eq!(column_indices.to_vec(), vec![0, 0, 0, 1, 1, 1]);
+        assert!(t.sort(2, false).is_err());
+    }
+
+    #[test]
+    fn argsort_strided_with_nan() {
+        let t = crate::tensor![[2.0, f64::NAN], [1.0, 0.5], [3.0, 4.0]];
+        let columns = t.transpose(0, 1).unwrap();
+
+        assert_eq!(
+            columns.argsort(1, false).unwrap().to_vec(),
+            vec![1, 0, 2, 1, 2, 0]
+        );
+        assert_eq!(
+            columns.argsort(1, true).unwrap().to_vec(),
+            vec![2, 0, 1, 0, 2, 1]
+        );
+    }
+
+    #[test]
+    fn kthvalue() {
+        let t = crate::tensor![[5, 1, 4], [2, 2, 9]];
+
+        let (values, indices) = t.kthvalue(2, 1, false).unwrap();
+
+        assert_eq!(values.to_vec(), vec![4, 2]);
+        assert_eq!(indices.to_vec(), vec![2, 1]);
+        assert_eq!(t.kthvalue(1, 0, true).unwrap().0.shape(), &[1, 3]);
+        assert_eq!(
+            t.kthvalue(4, 1, false).err(),
+            Some(TensorError::IndexOutOfRange { index: 4, size: 3 })
+        );
+    }
+
+    #[test]
+    fn mode() {
+        let t = crate::tensor![[3, 1, 3, 1, 2], [7, 7, 0, 2, 7]];
+
+        let (values, indices) = t.mode(1, false).unwrap();
+
+        assert_eq!(values.to_vec(), vec![1, 7]);
+        assert_eq!(indices.to_vec(), vec![1, 0]);
+        assert_eq!(
+            crate::tensor![[0, 0]].mode(0, true).unwrap().1.to_vec(),
+            vec![0, 0]
+        );
+    }
+}
@@ -1,65 +1,276 @@
+use crate::shape::Shape;
 use std::rc::Rc;
 use std::cell::RefCell;
 
 type SharedData<T> = Rc<RefCell<Vec<T>>>;
 
-#[derive(Clone, PartialEq, Eq)]
+/// Strided view over a reference-counted buffer.
+///
+/// Several `BaseTensor`s may point at the same buffer (see [`BaseTensor::share`]), but
+/// [`Clone`] always performs a deep copy so that mutating one tensor can never corrupt another.
+#[derive(PartialEq, Eq)]
 pub struct BaseTensor<T> {
     pub data: SharedData<T>,
-    pub shape: Vec<usize>,
-    pub strides: Vec<usize>,
+    pub shape: Shape,
+    pub strides: Vec<isize>,
     pub offset: usize,
 }
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Tensor<T> {
     pub base: BaseTensor<T>,
+    /// Whether gradients should be tracked for this tensor.
+    pub requires_grad: bool,
 }
 
-impl<T> std::ops::Add for BaseTensor<T>
-where
-    T: std::ops::Add<Output = T> + Copy + Default,
-{
-    type Output = BaseTensor<T>;
+/// Calls `f` with every multi-index of `shape`, in row-major order.
+pub(crate) fn for_each_index(shape: &[usize], mut f: impl FnMut(&[usize])) {
+    if shape.contains(&0) {
+        return;
+    }
+    let mut index = vec![0; shape.len()];
+    loop {
+        f(&index);
+        let Some(dim) = (0..shape.len()).rev().find(|&d| index[d] + 1 < shape[d]) else {
+            return;
+        };
+        index[dim] += 1;
+        index[dim + 1..].fill(0);
+    }
+}
 
-    fn add(self, rhs: Self) -> Self::Output {
-        // 1. Check if shapes match
-        if self.shape != rhs.shape {
-            // In a real implementation, this should panic or return a Result.
-            panic!("BaseTensor shapes must match for addition: left {:?} vs right {:?}", self.shape, rhs.shape);
+/// Iterator over the buffer positions of a strided layout, in logical row-major order.
+pub struct StorageIndices {
+    shape: Vec<usize>,
+    strides: Vec<isize>,
+    index: Vec<usize>,
+    position: isize,
+    remaining: usize,
+}
+
+impl StorageIndices {
+    pub fn new(shape: &[usize], strides: &[isize], offset: usize) -> Self {
+        StorageIndices {
+            shape: shape.to_vec(),
+            strides: strides.to_vec(),
+            index: vec![0; shape.len()],
+            position: offset as isize,
+            remaining: shape.iter().product(),
         }
+    }
+}
 
-        let total_size = self.shape.iter().product();
+impl Iterator for StorageIndices {
+    type Item = usize;
 
-        // 2. Create a new Vec to store the result
-        let mut result_data = Vec::with_capacity(total_size);
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let current = self.position as usize;
+        self.remaining -= 1;
 
-        // Get shared data references. `borrow()` is needed for RefCell.
-        let left_data = self.data.borrow();
-        let right_data = rhs.data.borrow();
+        // Advance the multi-index like an odometer, carrying into the next outer dimension.
+        if self.remaining > 0 {
+            for dim in (0..self.shape.len()).rev() {
+                self.index[dim] += 1;
+                self.position += self.strides[dim];
+                if self.index[dim] < self.shape[dim] {
+                    break;
+                }
+                self.position -= self.strides[dim] * self.shape[dim] as isize;
+                self.index[dim] = 0;
+            }
+        }
+        Some(current)
+    }
 
-        // Simplified addition assuming contiguous and simple data layout.
-        // A full implementation would need an iterator that respects shape, strides, and offset.
-        for i in 0..total_size {
-            // Element-wise addition. We need to use `T::add` or the `+` operator.
-            // Since T: Add<Output = T>, we can use the + operator.
-            // Using `*` to dereference the Copy type and apply the operation.
-            let sum = left_data[i].add(right_data[i]);
-            result_data.push(sum);
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for StorageIndices {}
+
+impl<T> BaseTensor<T> {
+    /// Wraps `data` in a fresh buffer, laid out contiguously in row-major order.
+    ///
+    /// Panics if `shape` overflows or does not describe exactly `data.len()` elements.
+    pub fn from_vec(data: Vec<T>, shape: &[usize]) -> Self {
+        let shape = Shape::from(shape);
+        let numel = shape
+            .checked_numel()
+            .unwrap_or_else(|| panic!("BaseTensor shape {:?} overflows usize", shape));
+        if numel != data.len() {
+            panic!(
+                "BaseTensor shape {:?} requires {} elements but {} were given",
+                shape,
+                numel,
+                data.len()
+            );
         }
 
-        // 3. Construct the result BaseTensor
         BaseTensor {
-            // Wrap the new data in Rc<RefCell<...>> for the result
-            data: Rc::new(RefCell::new(result_data)),
-            // The shape, strides, and offset are the same as the inputs (for a simple view)
-            shape: self.shape,
-            strides: self.strides,
-            offset: 0, // New data starts at offset 0
+            data: Rc::new(RefCell::new(data)),
+            strides: shape.default_strides(),
+            shape,
+            offset: 0,
+        }
+    }
+
+    /// Number of dimensions.
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// Total number of logical elements.
+    pub fn numel(&self) -> usize {
+        self.shape.numel()
+    }
+
+    /// Buffer positions of every logical element, in row-major order.
+    pub fn storage_indices(&self) -> StorageIndices {
+        StorageIndices::new(&self.shape, &self.strides, self.offset)
+    }
+
+    /// Buffer position of the element at the multi-index `index`, which must be in bounds.
+    pub fn storage_offset(&self, index: &[usize]) -> usize {
+        let position = index
+            .iter()
+            .zip(&self.strides)
+            .fold(self.offset as isize, |acc, (&i, &stride)| acc + i as isize * stride);
+        position as usize
+    }
+
+    /// Whether the logical elements occupy one compact row-major run of the buffer.
+    pub fn is_contiguous(&self) -> bool {
+        let expected = self.shape.default_strides();
+        self.shape
+            .iter()
+            .zip(self.strides.iter().zip(&expected))
+            .all(|(&dim, (stride, expected))| dim <= 1 || stride == expected)
+    }
+
+    /// Returns a new handle to the same buffer. Writes through either handle are visible to both.
+    pub fn share(&self) -> Self {
+        BaseTensor {
+            data: Rc::clone(&self.data),
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            offset: self.offset,
+        }
+    }
+
+    /// Wraps this buffer view in a [`Tensor`] with the given `requires_grad`. View
+    /// constructors use this to carry the flag over from the tensor they view.
+    pub(crate) fn into_tensor(self, requires_grad: bool) -> Tensor<T> {
+        Tensor {
+            base: self,
+            requires_grad,
+        }
+    }
+}
+
+impl<T: Clone> BaseTensor<T> {
+    /// Copies the underlying buffer into a freshly allocated one, keeping the same layout.
+    pub fn deep_clone(&self) -> Self {
+        BaseTensor {
+            data: Rc::new(RefCell::new(self.data.borrow().clone())),
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            offset: self.offset,
+        }
+    }
+}
+
+impl<T: Clone> Clone for BaseTensor<T> {
+    /// Deep copy; use [`BaseTensor::share`] to alias the buffer instead.
+    fn clone(&self) -> Self {
+        self.deep_clone()
+    }
+}
+
+impl<T> From<BaseTensor<T>> for Tensor<T> {
+    fn from(base: BaseTensor<T>) -> Self {
+        Tensor {
+            base,
+            requires_grad: false,
+        }
+    }
+}
+
+impl<T> Tensor<T> {
+    /// Builds a contiguous tensor from `data` with the given `shape`.
+    ///
+    /// Panics if `shape` does not describe exactly `data.len()` elements.
+    pub fn from_vec(data: Vec<T>, shape: &[usize]) -> Self {
+        BaseTensor::from_vec(data, shape).into()
+    }
+
+    /// Returns a new handle to the same buffer. Writes through either handle are visible to both.
+    pub fn share(&self) -> Self {
+        Tensor {
+            base: self.base.share(),
+            requires_grad: self.requires_grad,
+        }
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.base.shape
+    }
+
+    pub fn strides(&self) -> &[isize] {
+        &self.base.strides
+    }
+
+    pub fn ndim(&self) -> usize {
+        self.base.ndim()
+    }
+
+    pub fn numel(&self) -> usize {
+        self.base.numel()
+    }
+
+    /// Whether the logical elements occupy one compact row-major run of the buffer.
+    pub fn is_contiguous(&self) -> bool {
+        self.base.is_contiguous()
+    }
+}
+
+impl<T: Clone> Tensor<T> {
+    /// Copies the underlying buffer into a freshly allocated one, keeping the same layout.
+    pub fn deep_clone(&self) -> Self {
+        Tensor {
+            base: self.base.deep_clone(),
+            requires_grad: self.requires_grad,
+        }
+    }
+
+    /// Returns this tensor if it is already contiguous (sharing the buffer), or a compact
+    /// row-major copy otherwise.
+    pub fn contiguous(&self) -> Self {
+        if self.is_contiguous() {
+            return self.share();
+        }
+        Tensor {
+            base: BaseTensor::from_vec(self.to_vec(), self.shape()),
+            requires_grad: self.requires_grad,
         }
     }
 }
 
+impl<T> std::ops::Add for BaseTensor<T>
+where
+    T: std::ops::Add<Output = T> + Copy + Default,
+{
+    type Output = BaseTensor<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Broadcasting and strided iteration live on `Tensor`; the result is a fresh contiguous buffer.
+        (Tensor::from(self) + Tensor::from(rhs)).base
+    }
+}
+
 impl<T> std::ops::Add for Tensor<T>
 where
     // T must support addition and its output must be T
@@ -72,9 +283,9 @@ where
 
     // The method to perform the addition
     fn add(self, rhs: Self) -> Self::Output {
-        Tensor {
-            base: self.base + rhs.base,
-        }
+        // Operands are broadcast to a common shape; see `Tensor::try_add` for the checked version.
+        self.try_add(&rhs)
+            .unwrap_or_else(|e| panic!("Tensor addition failed: {}", e))
     }
 }
 
@@ -89,37 +300,9 @@ where
     type Output = Tensor<T>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        // 1. Check if shapes match
-        if self.base.shape != rhs.base.shape {
-            // In a real implementation, this should panic or return a Result.
-            panic!("Tensor shapes must match for addition: left {:?} vs right {:?}", self.base.shape, rhs.base.shape);
-        }
-
-        let total_size = self.base.shape.iter().product();
-
-        // 2. Create a new Vec to store the result
-        let mut result_data = Vec::with_capacity(total_size);
-
-        // Get shared data references. `borrow()` is needed for RefCell.
-        let left_data = self.base.data.borrow();
-        let right_data = rhs.base.data.borrow();
-
-        for i in 0..total_size {
-            let sum = left_data[i].sub(right_data[i]);
-            result_data.push(sum);
-        }
-
-        // 3. Construct the result Tensor
-        Tensor {
-            base: BaseTensor {
-                // Wrap the new data in Rc<RefCell<...>> for the result
-                data: Rc::new(RefCell::new(result_data)),
-                // The shape, strides, and offset are the same as the inputs (for a simple view)
-                shape: self.base.shape,
-                strides: self.base.strides,
-                offset: 0, // New data starts at offset 0
-            },
-        }
+        // Operands are broadcast to a common shape; see `Tensor::try_sub` for the checked version.
+        self.try_sub(&rhs)
+            .unwrap_or_else(|e| panic!("Tensor subtraction failed: {}", e))
     }
 }
 
@@ -137,18 +320,20 @@ mod tests {
             let left = Tensor {
                 base: BaseTensor {
                     data: Rc::new(RefCell::new(vec![1, 2, 3, 4])),
-                    shape: vec![2, 2],
+                    shape: vec![2, 2].into(),
                     strides: vec![2, 1],
                     offset: 0,
                 },
+                requires_grad: false,
             };
             let right = Tensor {
                 base: BaseTensor {
                     data: Rc::new(RefCell::new(vec![10, 20, 30, 40])),
-                    shape: vec![2, 2],
+                    shape: vec![2, 2].into(),
                     strides: vec![2, 1],
                     offset: 0,
                 },
+                requires_grad: false,
             };
 
             let result = left + right;
@@ -161,18 +346,20 @@ mod tests {
             let left = Tensor {
                 base: BaseTensor {
                     data: Rc::new(RefCell::new(vec![10, 20, 30, 40])),
-                    shape: vec![2, 2],
+                    shape: vec![2, 2].into(),
                     strides: vec![2, 1],
                     offset: 0,
                 },
+                requires_grad: false,
             };
             let right = Tensor {
                 base: BaseTensor {
                     data: Rc::new(RefCell::new(vec![1, 2, 3, 4])),
-                    shape: vec![2, 2],
+                    shape: vec![2, 2].into(),
                     strides: vec![2, 1],
                     offset: 0,
                 },
+                requires_grad: false,
             };
 
             let result = left - right;
@@ -185,23 +372,91 @@ mod tests {
             let left = Tensor {
                 base: BaseTensor {
                     data: Rc::new(RefCell::new(vec![1, 2, 3, 4])),
-                    shape: vec![2, 2],
+                    shape: vec![2, 2].into(),
                     strides: vec![2, 1],
                     offset: 0,
                 },
+                requires_grad: false,
             };
             let right = Tensor {
                 base: BaseTensor {
                     data: Rc::new(RefCell::new(vec![10, 20, 30, 40])),
-                    shape: vec![2, 2],
+                    shape: vec![2, 2].into(),
                     strides: vec![2, 1],
                     offset: 0,
                 },
+                requires_grad: false,
             };
 
             let result = left - right;
 
             assert_eq!(*result.base.data.borrow(), vec![-9, -18, -27, -36]);
         }
+
+        #[test]
+        fn add_broadcasts() {
+            let left = Tensor::from_vec(vec![1, 2, 3, 4, 5, 6], &[2, 3]);
+            let right = Tensor::from_vec(vec![10, 20, 30], &[3]);
+
+            let result = left + right;
+
+            assert_eq!(result.shape(), &[2, 3]);
+            assert_eq!(result.to_vec(), vec![11, 22, 33, 14, 25, 36]);
+        }
+
+        #[test]
+        fn sub_broadcasts_both_operands() {
+            let left = Tensor::from_vec(vec![10, 20, 30, 40], &[4, 1]);
+            let right = Tensor::from_vec(vec![1, 2, 3, 4, 5], &[1, 5]);
+
+            let result = left - right;
+
+            assert_eq!(result.shape(), &[4, 5]);
+            assert_eq!(result.to_vec()[..5], [9, 8, 7, 6, 5]);
+            assert_eq!(result.to_vec()[15..], [39, 38, 37, 36, 35]);
+        }
+
+        #[test]
+        #[should_panic(expected = "cannot be broadcast")]
+        fn add_incompatible_shapes_panics() {
+            let _ = Tensor::from_vec(vec![1, 2, 3], &[3]) + Tensor::from_vec(vec![1, 2], &[2]);
+        }
+
+        #[test]
+        fn contiguous() {
+            let t = Tensor::from_vec(vec![1, 2, 3, 4, 5, 6], &[2, 3]);
+            let transposed = t.transpose(0, 1).unwrap();
+
+            assert!(t.is_contiguous());
+            assert!(!transposed.is_contiguous());
+            assert!(Rc::ptr_eq(&t.base.data, &t.contiguous().base.data));
+
+            let compact = transposed.contiguous();
+            assert!(compact.is_contiguous());
+            assert_eq!(compact.strides(), &[2, 1]);
+            assert_eq!(*compact.base.data.borrow(), vec![1, 4, 2, 5, 3, 6]);
+        }
+
+        #[test]
+        fn clone_is_deep() {
+            let original = Tensor::from_vec(vec![1, 2, 3, 4], &[2, 2]);
+            let copy = original.clone();
+
+            copy.base.data.borrow_mut()[0] = 100;
+
+            assert_eq!(*original.base.data.borrow(), vec![1, 2, 3, 4]);
+            assert!(!Rc::ptr_eq(&original.base.data, &copy.base.data));
+        }
+
+        #[test]
+        fn share_aliases_buffer() {
+            let original = Tensor::from_vec(vec![1, 2, 3, 4], &[2, 2]);
+            let shared = original.share();
+
+            shared.base.data.borrow_mut()[0] = 100;
+
+            assert_eq!(*original.base.data.borrow(), vec![100, 2, 3, 4]);
+            assert!(Rc::ptr_eq(&original.base.data, &shared.base.data));
+        }
     }
 }
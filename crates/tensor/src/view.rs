@@ -0,0 +1,777 @@
+use crate::error::{Result, TensorError};
+use crate::shape::Shape;
+use crate::types::{BaseTensor, Tensor};
+
+/// Resolves a reshape target that may contain a single `-1` into concrete dimensions.
+fn infer_shape(numel: usize, shape: &[usize], target: &[isize]) -> Result<Vec<usize>> {
+    let invalid = || TensorError::InvalidReshape {
+        shape: shape.to_vec(),
+        target: target.to_vec(),
+    };
+
+    let mut inferred = None;
+    let mut known = 1usize;
+    let mut dims = Vec::with_capacity(target.len());
+    for (i, &dim) in target.iter().enumerate() {
+        match dim {
+            -1 if inferred.is_none() => {
+                inferred = Some(i);
+                dims.push(1);
+            }
+            0.. => {
+                known = known.checked_mul(dim as usize).ok_or_else(invalid)?;
+                dims.push(dim as usize);
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    match inferred {
+        Some(i) if known != 0 && numel.is_multiple_of(known) => dims[i] = numel / known,
+        Some(_) => return Err(invalid()),
+        None if known != numel => return Err(invalid()),
+        None => {}
+    }
+    Ok(dims)
+}
+
+/// Computes the shape that `left` and `right` broadcast to. See [`Shape::broadcast_with`].
+pub fn broadcast_shapes(left: &[usize], right: &[usize]) -> Result<Vec<usize>> {
+    Ok(Shape::from(left)
+        .broadcast_with(&Shape::from(right))?
+        .into_vec())
+}
+
+/// Checks that a strided layout has one stride per dimension and stays inside a buffer of
+/// `buffer_len` elements.
+pub(crate) fn check_layout(
+    shape: &[usize],
+    strides: &[isize],
+    offset: usize,
+    buffer_len: usize,
+) -> Result<()> {
+    if strides.len() != shape.len() {
+        return Err(TensorError::StridesMismatch {
+            shape: shape.to_vec(),
+            strides: strides.to_vec(),
+        });
+    }
+    let shape = Shape::from(shape);
+    if shape.checked_numel().is_none() {
+        return Err(TensorError::ShapeOverflow {
+            shape: shape.into_vec(),
+        });
+    }
+    if !shape.is_valid_view(strides, offset, buffer_len) {
+        return Err(TensorError::ViewOutOfBounds {
+            shape: shape.into_vec(),
+            strides: strides.to_vec(),
+            offset,
+            buffer_len,
+        });
+    }
+    Ok(())
+}
+
+/// Resolves a possibly negative dimension index against a tensor of rank `ndim`.
+/// Negative values count from the end, so `-1` is the last dimension.
+pub fn normalize_dim(dim: isize, ndim: usize) -> Result<usize> {
+    let resolved = if dim < 0 {
+        ndim.checked_sub(dim.unsigned_abs())
+    } else {
+        Some(dim as usize)
+    };
+    resolved
+        .filter(|&d| d < ndim)
+        .ok_or(TensorError::DimOutOfRange { dim, ndim })
+}
+
+/// Checks that `dim` indexes one of the `ndim` dimensions.
+pub(crate) fn check_dim(dim: usize, ndim: usize) -> Result<usize> {
+    // Anything that does not fit in `isize` is out of range anyway.
+    normalize_dim(isize::try_from(dim).unwrap_or(isize::MAX), ndim)
+}
+
+impl<T> Tensor<T> {
+    /// Returns a view with dimensions `dim0` and `dim1` swapped. No data is copied.
+    pub fn transpose(&self, dim0: usize, dim1: usize) -> Result<Tensor<T>> {
+        check_dim(dim0, self.ndim())?;
+        check_dim(dim1, self.ndim())?;
+
+        let mut view = self.base.share();
+        view.shape.swap(dim0, dim1);
+        view.strides.swap(dim0, dim1);
+        Ok(view.into_tensor(self.requires_grad))
+    }
+
+    /// Returns a view whose dimension `i` is dimension `dims[i]` of this tensor.
+    /// No data is copied.
+    pub fn permute(&self, dims: &[usize]) -> Result<Tensor<T>> {
+        let mut seen = vec![false; self.ndim()];
+        let is_permutation = dims.len() == self.ndim()
+            && dims
+                .iter()
+                .all(|&d| d < seen.len() && !std::mem::replace(&mut seen[d], true));
+        if !is_permutation {
+            return Err(TensorError::InvalidPermutation {
+                dims: dims.to_vec(),
+                ndim: self.ndim(),
+            });
+        }
+
+        Ok(BaseTensor {
+            shape: dims.iter().map(|&d| self.shape()[d]).collect(),
+            strides: dims.iter().map(|&d| self.strides()[d]).collect(),
+            ..self.base.share()
+        }
+        .into_tensor(self.requires_grad))
+    }
+
+    /// Like [`Tensor::transpose`], but accepts negative dimensions.
+    pub fn swapaxes(&self, axis0: isize, axis1: isize) -> Result<Tensor<T>> {
+        self.transpose(
+            normalize_dim(axis0, self.ndim())?,
+            normalize_dim(axis1, self.ndim())?,
+        )
+    }
+
+    /// Moves each dimension `source[i]` to position `destination[i]`, keeping the remaining
+    /// dimensions in their original relative order. Negative dimensions count from the end.
+    /// No data is copied.
+    pub fn movedim(&self, source: &[isize], destination: &[isize]) -> Result<Tensor<T>> {
+        if source.len() != destination.len() {
+            return Err(TensorError::LengthMismatch {
+                expected: source.len(),
+                actual: destination.len(),
+            });
+        }
+        let ndim = self.ndim();
+        let normalize_unique = |dims: &[isize]| {
+            let dims = dims
+                .iter()
+                .map(|&d| normalize_dim(d, ndim))
+                .collect::<Result<Vec<_>>>()?;
+            let mut seen = vec![false; ndim];
+            if dims.iter().any(|&d| std::mem::replace(&mut seen[d], true)) {
+                return Err(TensorError::InvalidPermutation { dims, ndim });
+            }
+            Ok(dims)
+        };
+        let source = normalize_unique(source)?;
+        let destination = normalize_unique(destination)?;
+
+        let mut order = vec![None; ndim];
+        for (&src, &dst) in source.iter().zip(&destination) {
+            order[dst] = Some(src);
+        }
+        let mut rest = (0..ndim).filter(|d| !source.contains(d));
+        let order = order
+            .into_iter()
+            .map(|slot| slot.or_else(|| rest.next()).expect("every slot is filled"))
+            .collect::<Vec<_>>();
+        self.permute(&order)
+    }
+
+    /// Returns a view with dimension `dim` removed. The dimension must have size 1.
+    pub fn squeeze(&self, dim: usize) -> Result<Tensor<T>> {
+        check_dim(dim, self.ndim())?;
+        if self.shape()[dim] != 1 {
+            return Err(TensorError::NotSingletonDim {
+                dim,
+                size: self.shape()[dim],
+            });
+        }
+
+        let mut view = self.base.share();
+        view.shape.remove(dim);
+        view.strides.remove(dim);
+        Ok(view.into_tensor(self.requires_grad))
+    }
+
+    /// Returns a view with every size-1 dimension removed.
+    pub fn squeeze_all(&self) -> Tensor<T> {
+        let (shape, strides): (Vec<usize>, Vec<isize>) = self
+            .shape()
+            .iter()
+            .zip(self.strides())
+            .filter(|&(&size, _)| size != 1)
+            .unzip();
+        BaseTensor {
+            shape: shape.into(),
+            strides,
+            ..self.base.share()
+        }
+        .into_tensor(self.requires_grad)
+    }
+
+    /// Returns a view with a size-1 dimension inserted at position `dim` (`0..=ndim`).
+    pub fn unsqueeze(&self, dim: usize) -> Result<Tensor<T>> {
+        check_dim(dim, self.ndim() + 1)?;
+
+        // Any stride works for a size-1 dimension; pick the one a contiguous tensor would have.
+        let stride = match dim {
+            d if d < self.ndim() => self.strides()[d] * self.shape()[d] as isize,
+            _ => 1,
+        };
+        let mut view = self.base.share();
+        view.shape.insert(dim, 1);
+        view.strides.insert(dim, stride);
+        Ok(view.into_tensor(self.requires_grad))
+    }
+
+    /// Returns a view of this tensor broadcast to `shape`, following NumPy rules: dimensions
+    /// are aligned from the right, and size-1 or missing dimensions are repeated by giving
+    /// them stride 0. No data is copied.
+    pub fn broadcast_to(&self, shape: &[usize]) -> Result<Tensor<T>> {
+        let mismatch = || TensorError::BroadcastMismatch {
+            left: self.shape().to_vec(),
+            right: shape.to_vec(),
+        };
+        let leading = shape.len().checked_sub(self.ndim()).ok_or_else(mismatch)?;
+
+        let mut strides = vec![0; shape.len()];
+        for (i, (&size, &stride)) in self.shape().iter().zip(self.strides()).enumerate() {
+            match shape[leading + i] {
+                target if target == size => strides[leading + i] = stride,
+                _ if size == 1 => {}
+                _ => return Err(mismatch()),
+            }
+        }
+
+        Ok(BaseTensor {
+            shape: shape.into(),
+            strides,
+            ..self.base.share()
+        }
+        .into_tensor(self.requires_grad))
+    }
+
+    /// Like [`Tensor::broadcast_to`], but a `-1` entry keeps the size of the corresponding
+    /// existing dimension.
+    pub fn expand(&self, shape: &[isize]) -> Result<Tensor<T>> {
+        let leading = shape.len().saturating_sub(self.ndim());
+        let resolved = shape
+            .iter()
+            .enumerate()
+            .map(|(i, &size)| match size {
+                -1 if i >= leading => Ok(self.shape()[i - leading]),
+                0.. => Ok(size as usize),
+                _ => Err(TensorError::InvalidReshape {
+                    shape: self.shape().to_vec(),
+                    target: shape.to_vec(),
+                }),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.broadcast_to(&resolved)
+    }
+
+    /// Returns a view over the same buffer with an arbitrary layout.
+    ///
+    /// This enables stride tricks such as sliding windows, where several logical elements
+    /// read the same buffer position. The layout is checked so that no element can fall
+    /// outside the buffer.
+    pub fn as_strided(
+        &self,
+        shape: &[usize],
+        strides: &[isize],
+        offset: usize,
+    ) -> Result<Tensor<T>> {
+        check_layout(shape, strides, offset, self.base.data.borrow().len())?;
+
+        Ok(BaseTensor {
+            shape: shape.into(),
+            strides: strides.to_vec(),
+            offset,
+            ..self.base.share()
+        }
+        .into_tensor(self.requires_grad))
+    }
+
+    /// Returns a view of every window of `size` consecutive entries along `dim`, taken every
+    /// `step` entries. Dimension `dim` becomes the window count and a new trailing dimension
+    /// of length `size` indexes within each window. No data is copied, so overlapping windows
+    /// read the same buffer elements.
+    pub fn unfold(&self, dim: usize, size: usize, step: usize) -> Result<Tensor<T>> {
+        check_dim(dim, self.ndim())?;
+        let dim_size = self.shape()[dim];
+        if step == 0 || size > dim_size {
+            return Err(TensorError::InvalidWindow {
+                size,
+                step,
+                dim_size,
+            });
+        }
+
+        let mut view = self.base.share();
+        let stride = view.strides[dim];
+        view.shape[dim] = (dim_size - size) / step + 1;
+        view.strides[dim] = stride * step as isize;
+        view.shape.push(size);
+        view.strides.push(stride);
+        Ok(view.into_tensor(self.requires_grad))
+    }
+
+    /// Returns a view with the order of elements reversed along each of `dims`, by negating
+    /// the corresponding strides. No data is copied.
+    pub fn flip(&self, dims: &[usize]) -> Result<Tensor<T>> {
+        let mut view = self.base.share();
+        let mut flipped = vec![false; self.ndim()];
+        for &dim in dims {
+            check_dim(dim, self.ndim())?;
+            if std::mem::replace(&mut flipped[dim], true) {
+                continue;
+            }
+            if view.shape[dim] > 0 {
+                let last = (view.shape[dim] - 1) as isize * view.strides[dim];
+                view.offset = (view.offset as isize + last) as usize;
+            }
+            view.strides[dim] = -view.strides[dim];
+        }
+        Ok(view.into_tensor(self.requires_grad))
+    }
+
+    /// Returns a view of `len` entries of dimension `dim`, starting at `start`.
+    /// No data is copied; only the offset and shape change.
+    pub fn narrow(&self, dim: usize, start: usize, len: usize) -> Result<Tensor<T>> {
+        check_dim(dim, self.ndim())?;
+        let size = self.shape()[dim];
+        if start.checked_add(len).is_none_or(|end| end > size) {
+            return Err(TensorError::SliceOutOfRange {
+                dim,
+                start,
+                len,
+                size,
+            });
+        }
+
+        let mut view = self.base.share();
+        view.offset = (view.offset as isize + start as isize * view.strides[dim]) as usize;
+        view.shape[dim] = len;
+        Ok(view.into_tensor(self.requires_grad))
+    }
+
+    /// Returns a view of the diagonal of the matrices spanned by `dim1` and `dim2`. Both
+    /// dimensions are removed and the diagonal becomes the last dimension. A positive
+    /// `offset` selects a diagonal above the main one, a negative one below it.
+    pub fn diagonal(&self, offset: isize, dim1: usize, dim2: usize) -> Result<Tensor<T>> {
+        check_dim(dim1, self.ndim())?;
+        check_dim(dim2, self.ndim())?;
+        if dim1 == dim2 {
+            return Err(TensorError::RepeatedDim { dim: dim1 });
+        }
+
+        let (rows, cols) = (self.shape()[dim1] as isize, self.shape()[dim2] as isize);
+        let (row_stride, col_stride) = (self.strides()[dim1], self.strides()[dim2]);
+        let len = if offset >= 0 {
+            rows.min(cols - offset)
+        } else {
+            (rows + offset).min(cols)
+        }
+        .max(0) as usize;
+        let start = if len == 0 {
+            0
+        } else if offset >= 0 {
+            offset * col_stride
+        } else {
+            -offset * row_stride
+        };
+
+        let mut view = self.base.share();
+        view.offset = (view.offset as isize + start) as usize;
+        for dim in [dim1.max(dim2), dim1.min(dim2)] {
+            view.shape.remove(dim);
+            view.strides.remove(dim);
+        }
+        view.shape.push(len);
+        view.strides.push(row_stride + col_stride);
+        Ok(view.into_tensor(self.requires_grad))
+    }
+}
+
+impl<T: Clone> Tensor<T> {
+    /// Returns a tensor with the same elements in row-major order but a new `shape`.
+    ///
+    /// At most one dimension may be `-1`, in which case its size is inferred. The result
+    /// shares the buffer when this tensor is contiguous, and is a copy otherwise.
+    pub fn reshape(&self, shape: &[isize]) -> Result<Tensor<T>> {
+        let shape = Shape::from(infer_shape(self.numel(), self.shape(), shape)?);
+
+        if self.is_contiguous() {
+            Ok(BaseTensor {
+                strides: shape.default_strides(),
+                shape,
+                ..self.base.share()
+            }
+            .into_tensor(self.requires_grad))
+        } else {
+            Ok(BaseTensor::from_vec(self.to_vec(), &shape).into_tensor(self.requires_grad))
+        }
+    }
+
+    /// Merges dimensions `start_dim..=end_dim` into one. The result shares the buffer when the
+    /// merged dimensions are laid out contiguously with respect to each other, and is a copy
+    /// otherwise. A zero-dimensional tensor flattens to shape `[1]`.
+    pub fn flatten(&self, start_dim: usize, end_dim: usize) -> Result<Tensor<T>> {
+        if self.ndim() == 0 {
+            return self.reshape(&[1]);
+        }
+        check_dim(start_dim, self.ndim())?;
+        check_dim(end_dim, self.ndim())?;
+        if start_dim > end_dim {
+            return Err(TensorError::InvalidDimRange {
+                start: start_dim,
+                end: end_dim,
+            });
+        }
+
+        let (shape, strides) = (self.shape(), self.strides());
+        let merged = shape[start_dim..=end_dim].iter().product::<usize>();
+        let mut new_shape = shape[..start_dim].to_vec();
+        new_shape.push(merged);
+        new_shape.extend_from_slice(&shape[end_dim + 1..]);
+
+        let mergeable = (start_dim..end_dim)
+            .all(|i| shape[i] <= 1 || strides[i] == strides[i + 1] * shape[i + 1] as isize);
+        if !mergeable {
+            return Ok(
+                BaseTensor::from_vec(self.to_vec(), &new_shape).into_tensor(self.requires_grad)
+            );
+        }
+
+        let mut new_strides = strides[..start_dim].to_vec();
+        new_strides.push(strides[end_dim]);
+        new_strides.extend_from_slice(&strides[end_dim + 1..]);
+        Ok(BaseTensor {
+            shape: new_shape.into(),
+            strides: new_strides,
+            ..self.base.share()
+        }
+        .into_tensor(self.requires_grad))
+    }
+
+    /// Returns the elements as a contiguous 1-D copy in row-major order.
+    pub fn ravel(&self) -> Tensor<T> {
+        BaseTensor::from_vec(self.to_vec(), &[self.numel()]).into_tensor(self.requires_grad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::TensorError;
+    use crate::types::{BaseTensor, Tensor};
+    use std::rc::Rc;
+
+    #[test]
+    fn reshape_contiguous_is_view() {
+        let t = Tensor::arange(0, 6, 1);
+        let r = t.reshape(&[2, -1]).unwrap();
+
+        assert_eq!(r.shape(), &[2, 3]);
+        assert_eq!(r.strides(), &[3, 1]);
+        assert!(Rc::ptr_eq(&t.base.data, &r.base.data));
+    }
+
+    #[test]
+    fn reshape_strided_copies() {
+        let t = Tensor::from(BaseTensor {
+            shape: vec![3, 2].into(),
+            strides: vec![1, 3],
+            ..Tensor::arange(0, 6, 1).base
+        });
+        let r = t.reshape(&[6]).unwrap();
+
+        assert_eq!(r.to_vec(), vec![0, 3, 1, 4, 2, 5]);
+        assert!(!Rc::ptr_eq(&t.base.data, &r.base.data));
+    }
+
+    #[test]
+    fn transpose_swaps_strides() {
+        let t = Tensor::arange(0, 6, 1).reshape(&[2, 3]).unwrap();
+        let tt = t.transpose(0, 1).unwrap();
+
+        assert_eq!(tt.shape(), &[3, 2]);
+        assert_eq!(tt.strides(), &[1, 3]);
+        assert_eq!(tt.to_vec(), vec![0, 3, 1, 4, 2, 5]);
+        assert!(Rc::ptr_eq(&t.base.data, &tt.base.data));
+        assert_eq!(
+            t.transpose(0, 2).err(),
+            Some(TensorError::DimOutOfRange { dim: 2, ndim: 2 })
+        );
+    }
+
+    #[test]
+    fn permute() {
+        let t = Tensor::arange(0, 24, 1).reshape(&[2, 3, 4]).unwrap();
+        let p = t.permute(&[2, 0, 1]).unwrap();
+
+        assert_eq!(p.shape(), &[4, 2, 3]);
+        assert_eq!(p.strides(), &[1, 12, 4]);
+        assert_eq!(p.to_vec()[..6], [0, 4, 8, 12, 16, 20]);
+        assert!(t.permute(&[0, 0, 1]).is_err());
+        assert!(t.permute(&[0, 1]).is_err());
+    }
+
+    #[test]
+    fn squeeze_and_unsqueeze() {
+        let t = Tensor::<f32>::zeros(&[1, 3, 1, 2]);
+
+        assert_eq!(t.squeeze(0).unwrap().shape(), &[3, 1, 2]);
+        assert_eq!(t.squeeze_all().shape(), &[3, 2]);
+        assert_eq!(
+            t.squeeze(1).err(),
+            Some(TensorError::NotSingletonDim { dim: 1, size: 3 })
+        );
+
+        let u = t.squeeze_all().unsqueeze(2).unwrap();
+        assert_eq!(u.shape(), &[3, 2, 1]);
+        assert_eq!(u.strides(), &[2, 1, 1]);
+        assert!(u.unsqueeze(3).is_ok());
+        assert!(u.unsqueeze(4).is_err());
+    }
+
+    #[test]
+    fn broadcast_shapes() {
+        assert_eq!(super::broadcast_shapes(&[2, 3], &[3]), Ok(vec![2, 3]));
+        assert_eq!(super::broadcast_shapes(&[4, 1], &[1, 5]), Ok(vec![4, 5]));
+        assert_eq!(super::broadcast_shapes(&[], &[2, 1]), Ok(vec![2, 1]));
+        assert!(super::broadcast_shapes(&[3], &[4]).is_err());
+    }
+
+    #[test]
+    fn broadcast_to_uses_zero_strides() {
+        let row = Tensor::from(vec![1, 2, 3]).unsqueeze(0).unwrap();
+        let b = row.broadcast_to(&[2, 3]).unwrap();
+
+        assert_eq!(b.strides(), &[0, 1]);
+        assert_eq!(b.to_vec(), vec![1, 2, 3, 1, 2, 3]);
+        assert_eq!(
+            row.broadcast_to(&[2, 4]).err(),
+            Some(TensorError::BroadcastMismatch {
+                left: vec![1, 3],
+                right: vec![2, 4],
+            })
+        );
+    }
+
+    #[test]
+    fn expand_keeps_minus_one_dims() {
+        let col = Tensor::from(vec![1, 2]).reshape(&[2, 1]).unwrap();
+        let e = col.expand(&[3, -1, 2]).unwrap();
+
+        assert_eq!(e.shape(), &[3, 2, 2]);
+        assert_eq!(e.to_vec(), vec![1, 1, 2, 2, 1, 1, 2, 2, 1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn flatten() {
+        let t = Tensor::arange(0, 24, 1).reshape(&[2, 3, 4]).unwrap();
+        let f = t.flatten(1, 2).unwrap();
+
+        assert_eq!(f.shape(), &[2, 12]);
+        assert!(Rc::ptr_eq(&t.base.data, &f.base.data));
+        assert_eq!(t.flatten(0, 2).unwrap().shape(), &[24]);
+        assert_eq!(
+            t.flatten(2, 1).err(),
+            Some(TensorError::InvalidDimRange { start: 2, end: 1 })
+        );
+    }
+
+    #[test]
+    fn flatten_non_contiguous_copies() {
+        let t = Tensor::arange(0, 6, 1)
+            .reshape(&[2, 3])
+            .unwrap()
+            .transpose(0, 1)
+            .unwrap();
+        let f = t.flatten(0, 1).unwrap();
+
+        assert_eq!(f.to_vec(), vec![0, 3, 1, 4, 2, 5]);
+        assert!(!Rc::ptr_eq(&t.base.data, &f.base.data));
+        assert_eq!(t.ravel().to_vec(), f.to_vec());
+    }
+
+    #[test]
+    fn narrow() {
+        let t = Tensor::arange(0, 12, 1).reshape(&[3, 4]).unwrap();
+        let rows = t.narrow(0, 1, 2).unwrap();
+        let cols = t.narrow(1, 1, 2).unwrap();
+
+        assert_eq!(rows.to_vec(), vec![4, 5, 6, 7, 8, 9, 10, 11]);
+        assert_eq!(cols.to_vec(), vec![1, 2, 5, 6, 9, 10]);
+        assert!(Rc::ptr_eq(&t.base.data, &cols.base.data));
+        assert_eq!(
+            t.narrow(1, 3, 2).err(),
+            Some(TensorError::SliceOutOfRange {
+                dim: 1,
+                start: 3,
+                len: 2,
+                size: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn flip_uses_negative_strides() {
+        let t = Tensor::arange(0, 6, 1).reshape(&[2, 3]).unwrap();
+        let f = t.flip(&[1]).unwrap();
+        let both = t.flip(&[0, 1]).unwrap();
+
+        assert_eq!(f.strides(), &[3, -1]);
+        assert_eq!(f.to_vec(), vec![2, 1, 0, 5, 4, 3]);
+        assert_eq!(both.to_vec(), vec![5, 4, 3, 2, 1, 0]);
+        assert!(Rc::ptr_eq(&t.base.data, &both.base.data));
+    }
+
+    #[test]
+    fn views_of_flipped_tensor() {
+        let f = Tensor::arange(0, 6, 1).flip(&[0]).unwrap();
+
+        assert!(!f.is_contiguous());
+        assert_eq!(f.narrow(0, 1, 3).unwrap().to_vec(), vec![4, 3, 2]);
+        assert_eq!(f.reshape(&[2, 3]).unwrap().to_vec(), vec![5, 4, 3, 2, 1, 0]);
+        assert_eq!(f.contiguous().strides(), &[1]);
+    }
+
+    #[test]
+    fn as_strided_sliding_window() {
+        let t = Tensor::arange(0, 5, 1);
+        let windows = t.as_strided(&[3, 3], &[1, 1], 0).unwrap();
+
+        assert_eq!(windows.to_vec(), vec![0, 1, 2, 1, 2, 3, 2, 3, 4]);
+        assert!(Rc::ptr_eq(&t.base.data, &windows.base.data));
+    }
+
+    #[test]
+    fn as_strided_rejects_out_of_bounds() {
+        let t = Tensor::arange(0, 5, 1);
+
+        assert_eq!(
+            t.as_strided(&[3, 3], &[1, 1], 1).err(),
+            Some(TensorError::ViewOutOfBounds {
+                shape: vec![3, 3],
+                strides: vec![1, 1],
+                offset: 1,
+                buffer_len: 5,
+            })
+        );
+        assert!(t.as_strided(&[2], &[-1], 0).is_err());
+        assert!(t.as_strided(&[2], &[1, 1], 0).is_err());
+    }
+
+    #[test]
+    fn unfold() {
+        let t = Tensor::arange(0, 7, 1);
+        let frames = t.unfold(0, 3, 2).unwrap();
+
+        assert_eq!(frames.shape(), &[3, 3]);
+        assert_eq!(frames.to_vec(), vec![0, 1, 2, 2, 3, 4, 4, 5, 6]);
+        assert!(Rc::ptr_eq(&t.base.data, &frames.base.data));
+        assert_eq!(
+            t.unfold(0, 8, 1).err(),
+            Some(TensorError::InvalidWindow {
+                size: 8,
+                step: 1,
+                dim_size: 7,
+            })
+        );
+    }
+
+    #[test]
+    fn unfold_patches() {
+        let image = Tensor::arange(0, 16, 1).reshape(&[4, 4]).unwrap();
+        let patches = image.unfold(0, 2, 2).unwrap().unfold(1, 2, 2).unwrap();
+
+        assert_eq!(patches.shape(), &[2, 2, 2, 2]);
+        assert_eq!(patches.to_vec()[..4], [0, 1, 4, 5]);
+        assert_eq!(patches.to_vec()[12..], [10, 11, 14, 15]);
+    }
+
+    #[test]
+    fn normalize_dim() {
+        assert_eq!(super::normalize_dim(-1, 3), Ok(2));
+        assert_eq!(super::normalize_dim(1, 3), Ok(1));
+        assert_eq!(
+            super::normalize_dim(-4, 3),
+            Err(TensorError::DimOutOfRange { dim: -4, ndim: 3 })
+        );
+        assert!(super::normalize_dim(3, 3).is_err());
+    }
+
+    #[test]
+    fn movedim_and_swapaxes() {
+        let t = Tensor::<f32>::zeros(&[2, 3, 4, 5]);
+
+        assert_eq!(t.movedim(&[0], &[-1]).unwrap().shape(), &[3, 4, 5, 2]);
+        assert_eq!(t.movedim(&[-1, 0], &[0, 1]).unwrap().shape(), &[5, 2, 3, 4]);
+        assert_eq!(t.swapaxes(0, -1).unwrap().shape(), &[5, 3, 4, 2]);
+        assert!(t.movedim(&[0, 0], &[1, 2]).is_err());
+        assert!(t.movedim(&[0], &[1, 2]).is_err());
+    }
+
+    #[test]
+    fn reshape_rejects_invalid_targets() {
+        let t = Tensor::<f32>::zeros(&[4, 3]);
+
+        assert_eq!(
+            t.reshape(&[5, -1]).err(),
+            Some(TensorError::InvalidReshape {
+                shape: vec![4, 3],
+                target: vec![5, -1],
+            })
+        );
+        assert!(t.reshape(&[-1, -1]).is_err());
+        assert!(t.reshape(&[2, 2]).is_err());
+    }
+
+    #[test]
+    fn diagonal_views() {
+        let t = Tensor::arange(0, 12, 1).reshape(&[3, 4]).unwrap();
+
+        assert_eq!(t.diagonal(0, 0, 1).unwrap().to_vec(), vec![0, 5, 10]);
+        assert_eq!(t.diagonal(1, 0, 1).unwrap().to_vec(), vec![1, 6, 11]);
+        assert_eq!(t.diagonal(2, 0, 1).unwrap().to_vec(), vec![2, 7]);
+        assert_eq!(t.diagonal(-1, 0, 1).unwrap().to_vec(), vec![4, 9]);
+        assert_eq!(t.diagonal(5, 0, 1).unwrap().numel(), 0);
+        assert_eq!(t.diagonal(0, 1, 0).unwrap().to_vec(), vec![0, 5, 10]);
+        assert!(t.diagonal(0, 1, 1).is_err());
+
+        let batched = Tensor::arange(0, 8, 1).reshape(&[2, 2, 2]).unwrap();
+        let diagonals = batched.diagonal(0, 1, 2).unwrap();
+        assert_eq!(diagonals.shape(), &[2, 2]);
+        assert_eq!(diagonals.to_vec(), vec![0, 3, 4, 7]);
+        assert!(Rc::ptr_eq(&diagonals.base.data, &batched.base.data));
+    }
+
+    #[test]
+    fn views_keep_requires_grad() {
+        let mut t = Tensor::arange(0.0, 12.0, 1.0).reshape(&[3, 4]).unwrap();
+        t.requires_grad = true;
+        let transposed = t.transpose(0, 1).unwrap();
+
+        let views = [
+            t.reshape(&[4, 3]).unwrap(),
+            transposed.reshape(&[12]).unwrap(),
+            transposed.flatten(0, 1).unwrap(),
+            t.ravel(),
+            transposed,
+            t.permute(&[1, 0]).unwrap(),
+            t.narrow(1, 1, 2).unwrap(),
+            t.flip(&[0]).unwrap(),
+            t.unsqueeze(0).unwrap(),
+            t.unsqueeze(0).unwrap().squeeze(0).unwrap(),
+            t.unsqueeze(1).unwrap().squeeze_all(),
+            t.expand(&[2, -1, -1]).unwrap(),
+            t.unfold(1, 2, 2).unwrap(),
+            t.diagonal(0, 0, 1).unwrap(),
+            t.iter_axis(0).unwrap().next().unwrap(),
+        ];
+
+        assert!(views.iter().all(|view| view.requires_grad));
+        assert!(
+            !Tensor::arange(0, 4, 1)
+                .reshape(&[2, 2])
+                .unwrap()
+                .requires_grad
+        );
+    }
+}